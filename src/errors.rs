@@ -1 +1,4 @@
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod suggest;
+pub mod codes;
+pub mod warnings;
\ No newline at end of file
@@ -0,0 +1,577 @@
+use super::error::ETypes;
+
+//every distinct diagnostic has a stable code, 'G' then two digits for its ETypes kind and two for the diagnostic
+//codes are never reused or renumbered once released, so they can be searched for and looked up with 'gscript explain'
+pub struct ErrorCode {
+    pub code : &'static str,
+    pub etype : ETypes,
+    pub summary : &'static str,
+    //a longer description with an example and a fix, printed by 'gscript explain'
+    pub explanation : &'static str,
+}
+
+pub const INVALID_NUMBER : ErrorCode = ErrorCode { code : "G0101", etype : ETypes::SyntaxError,
+    summary : "A number literal could not be read",
+    explanation : "A run of digits (with at most one '.') must fit in a 32-bit Integer or Float.
+
+Example:
+    assign big = 99999999999;
+
+Fix: use a smaller number, integers range from -2147483648 to 2147483647.",
+};
+pub const EXPECTED_EXPRESSION : ErrorCode = ErrorCode { code : "G0102", etype : ETypes::SyntaxError,
+    summary : "Expected an expression",
+    explanation : "A value was needed here, such as a number, a string, a variable or a function call, but something else came first.
+
+Example:
+    assign x = ;
+
+Fix: give the missing value.
+    assign x = 0;",
+};
+pub const UNMATCHED_BRACE : ErrorCode = ErrorCode { code : "G0103", etype : ETypes::SyntaxError,
+    summary : "A '}' has no block to close",
+    explanation : "There are more closing braces than opening ones at the top level of the file.
+
+Example:
+    funct f() {
+        return 1;
+    };
+    };
+
+Fix: remove the extra '}', or add the '{' that it was meant to close.",
+};
+pub const EXPECTED_SEMICOLON : ErrorCode = ErrorCode { code : "G0104", etype : ETypes::SyntaxError,
+    summary : "Expected ';' after a statement",
+    explanation : "Every statement ends with a semicolon, including function, blueprint and if/while definitions after their closing '}'.
+
+Example:
+    assign x = 1
+    write(x);
+
+Fix: add the semicolon.
+    assign x = 1;
+    write(x);",
+};
+pub const EXPECTED_CLOSING_PAREN : ErrorCode = ErrorCode { code : "G0105", etype : ETypes::SyntaxError,
+    summary : "Expected ')'",
+    explanation : "A bracketed expression was not closed.
+
+Example:
+    assign y = (2 + 3;
+
+Fix: close the bracket.
+    assign y = (2 + 3);",
+};
+pub const UNEXPECTED_TOKEN : ErrorCode = ErrorCode { code : "G0106", etype : ETypes::SyntaxError,
+    summary : "A token cannot start an expression",
+    explanation : "The parser found a token, such as an operator or a keyword, where an expression should start.
+
+Example:
+    assign x = * 2;
+
+Fix: remove the token or put the missing value before it.
+    assign x = 3 * 2;",
+};
+pub const INVALID_IMPORT : ErrorCode = ErrorCode { code : "G0107", etype : ETypes::SyntaxError,
+    summary : "An import is not written correctly",
+    explanation : "An import either binds a whole module to a name, or lists the names it takes from a module.
+
+Example:
+    import shapes.gsc -> shapes;
+    import { area as } from \"shapes.gsc\";
+
+Fix: quote the file name, and give every 'as' a new name.
+    import \"shapes.gsc\" -> shapes;
+    import { area as shape_area } from \"shapes.gsc\";",
+};
+pub const INVALID_EXPORT : ErrorCode = ErrorCode { code : "G0108", etype : ETypes::SyntaxError,
+    summary : "'export' is not followed by a definition",
+    explanation : "Only variables, functions, blueprints and imports can be exported.
+
+Example:
+    export write(1);
+
+Fix: export a definition.
+    export funct greet() { write(1); };",
+};
+pub const EXPECTED_NAME : ErrorCode = ErrorCode { code : "G0109", etype : ETypes::SyntaxError,
+    summary : "Expected a name",
+    explanation : "A keyword that defines something, like 'assign', 'funct', 'param', 'blueprint', 'prop' or 'new', must be followed by a name. Names are also needed after a '.'.
+
+Example:
+    assign = 4;
+    funct (param a) { return a; };
+
+Fix: add the name.
+    assign count = 4;
+    funct double(param a) { return a * 2; };",
+};
+pub const INVALID_BLUEPRINT_MEMBER : ErrorCode = ErrorCode { code : "G0110", etype : ETypes::SyntaxError,
+    summary : "A blueprint member is not written correctly",
+    explanation : "The body of a blueprint can only contain 'prop', 'method', 'get' and 'set' members, optionally marked 'static' or 'private'.
+
+Example:
+    blueprint Car {
+        assign speed = 0;
+    };
+
+Fix: declare properties with 'prop'.
+    blueprint Car {
+        prop speed = 0;
+        method create() {};
+    };",
+};
+pub const INVALID_ACCESSOR : ErrorCode = ErrorCode { code : "G0111", etype : ETypes::SyntaxError,
+    summary : "A getter or setter has the wrong form",
+    explanation : "Getters take no parameters, setters take exactly one (the new value), and neither can be static.
+
+Example:
+    get speed(param unit) { return speed; };
+
+Fix: remove the parameter, or use a method instead.
+    get speed() { return speed; };",
+};
+pub const NOT_AN_OBJECT : ErrorCode = ErrorCode { code : "G0112", etype : ETypes::SyntaxError,
+    summary : "The dot operator was used on something that is not an object",
+    explanation : "Only objects, strings, modules and blueprints with static members have members that can be reached with '.'.
+
+Example:
+    assign n = 5;
+    write(n.size);
+
+Fix: use the dot operator on an object, or check which variable was meant.
+    assign s = \"hello\";
+    write(s.length());",
+};
+pub const UNREACHABLE_CODE : ErrorCode = ErrorCode { code : "G0113", etype : ETypes::SyntaxError,
+    summary : "A statement can never run",
+    explanation : "A statement after a 'return' or 'break' in the same block is never reached. This is the 'unreachable-code' warning, reported as an error when it is denied.
+
+Example:
+    funct f() {
+        return 1;
+        write(\"done\");
+    };
+
+Fix: remove the statement, or move it before the 'return'.",
+};
+pub const DIVIDE_BY_ZERO : ErrorCode = ErrorCode { code : "G0201", etype : ETypes::DivideByZeroError,
+    summary : "Division by zero",
+    explanation : "The right side of a division evaluated to 0 or 0.0.
+
+Example:
+    assign count = 0;
+    write(10 / count);
+
+Fix: check the divisor before dividing.
+    if (count != 0) {
+        write(10 / count);
+    };",
+};
+pub const CANNOT_READ_FILE : ErrorCode = ErrorCode { code : "G0301", etype : ETypes::FileError,
+    summary : "A script could not be read",
+    explanation : "The file given on the command line does not exist, or it could not be read.
+
+Example:
+    gscript run scirpt.gsc
+
+Fix: check the path, which is relative to the current directory.",
+};
+pub const MISSING_STD_FILE : ErrorCode = ErrorCode { code : "G0302", etype : ETypes::FileError,
+    summary : "A standard library file is missing from the module search path",
+    explanation : "With --std-from-disk, every file of the standard library is loaded from the module search path instead of the copies built into the executable.
+
+Example:
+    gscript --std-from-disk run game.gsc     (with no std directory around)
+
+Fix: set GSCRIPT_PATH to a directory holding the std files, or leave out --std-from-disk.",
+};
+pub const UNRECOGNIZED_CHARACTER : ErrorCode = ErrorCode { code : "G0401", etype : ETypes::TokenError,
+    summary : "A character is not part of GScript",
+    explanation : "The lexer found a character that does not start any token.
+
+Example:
+    assign price = $5;
+
+Fix: remove the character.
+    assign price = 5;",
+};
+pub const EXPECTED_TOKEN : ErrorCode = ErrorCode { code : "G0402", etype : ETypes::TokenError,
+    summary : "A specific token was expected",
+    explanation : "The grammar requires a particular token here, which the message names, such as a bracket or '->'.
+
+Example:
+    write(\"hi\";
+
+Fix: add the token.
+    write(\"hi\");",
+};
+pub const UNCLOSED_STRING : ErrorCode = ErrorCode { code : "G0403", etype : ETypes::TokenError,
+    summary : "A string runs past the end of its line",
+    explanation : "A string's closing '\"' was not found on the line it starts on. This is the 'unclosed-string' warning, reported as an error when it is denied.
+
+Example:
+    write(\"hello);
+
+Fix: close the string.
+    write(\"hello\");",
+};
+pub const KEYWORD_AS_VARIABLE : ErrorCode = ErrorCode { code : "G0601", etype : ETypes::VariableDefinitionError,
+    summary : "A keyword was used as a variable name",
+    explanation : "Keywords like 'if', 'while' or 'return' cannot be used as names.
+
+Example:
+    assign return = 1;
+
+Fix: pick another name.
+    assign result = 1;",
+};
+pub const VARIABLE_EXISTS : ErrorCode = ErrorCode { code : "G0602", etype : ETypes::VariableDefinitionError,
+    summary : "A variable is defined twice",
+    explanation : "'assign' creates a new variable, so it fails if the name is already taken in this scope or a parent scope. Imports also create variables.
+
+Example:
+    assign x = 1;
+    assign x = 2;
+
+Fix: assign to the existing variable without 'assign'.
+    assign x = 1;
+    x = 2;",
+};
+pub const ASSIGN_TO_UNDEFINED : ErrorCode = ErrorCode { code : "G0603", etype : ETypes::VariableDefinitionError,
+    summary : "A variable was reassigned before it was defined",
+    explanation : "'name = value;' changes an existing variable, it does not create one.
+
+Example:
+    total = 5;
+
+Fix: create the variable with 'assign' first.
+    assign total = 5;",
+};
+pub const UNUSED_VARIABLE : ErrorCode = ErrorCode { code : "G0604", etype : ETypes::VariableDefinitionError,
+    summary : "A variable is never read",
+    explanation : "A variable is given a value but nothing reads it, which often means a typo elsewhere. This is the 'unused-variable' warning, reported as an error when it is denied.
+
+Example:
+    assign total = 0;
+    write(totl);
+
+Fix: use the variable, remove it, or start its name with '_' if it is unused on purpose.",
+};
+pub const SHADOWED_VARIABLE : ErrorCode = ErrorCode { code : "G0605", etype : ETypes::VariableDefinitionError,
+    summary : "A variable hides one in an enclosing scope",
+    explanation : "A variable or parameter has the same name as a variable in an enclosing scope, so the outer one can't be reached inside it. This is the 'shadowed-variable' warning, reported as an error when it is denied.
+
+Example:
+    assign count = 0;
+    funct f(param count) { return count; };
+
+Fix: rename one of them.
+    funct f(param n) { return n; };",
+};
+pub const KEYWORD_AS_FUNCTION : ErrorCode = ErrorCode { code : "G0701", etype : ETypes::FunctionDefinitionError,
+    summary : "A keyword was used as a function name",
+    explanation : "Keywords cannot be used as names.
+
+Example:
+    funct while() { return 1; };
+
+Fix: pick another name.",
+};
+pub const FUNCTION_EXISTS : ErrorCode = ErrorCode { code : "G0702", etype : ETypes::FunctionDefinitionError,
+    summary : "A function is defined twice",
+    explanation : "Each function name can only be defined once in a scope, including names brought in with import.
+
+Example:
+    funct area() { return 1; };
+    funct area() { return 2; };
+
+Fix: rename one of them, or import it under another name with 'as'.",
+};
+pub const UNUSED_PARAMETER : ErrorCode = ErrorCode { code : "G0703", etype : ETypes::FunctionDefinitionError,
+    summary : "A function never reads one of its parameters",
+    explanation : "A parameter is never used in the function's body. This is the 'unused-parameter' warning, reported as an error when it is denied.
+
+Example:
+    funct double(param x, param y) { return x * 2; };
+
+Fix: remove the parameter, or start its name with '_' if it is unused on purpose.
+    funct double(param x) { return x * 2; };",
+};
+pub const WRONG_ARGUMENT_COUNT : ErrorCode = ErrorCode { code : "G0801", etype : ETypes::FunctionError,
+    summary : "A function was called with the wrong number of arguments",
+    explanation : "Functions and methods must be given exactly as many arguments as they have parameters.
+
+Example:
+    funct add(param a, param b) { return a + b; };
+    add(1);
+
+Fix: pass every argument.
+    add(1, 2);",
+};
+pub const UNDEFINED_FUNCTION : ErrorCode = ErrorCode { code : "G0802", etype : ETypes::FunctionError,
+    summary : "A function does not exist",
+    explanation : "No function with this name is defined in the current scope or any parent scope, and it is not a standard function.
+
+Example:
+    wrtie(\"hello\");
+
+Fix: check the spelling, or define or import the function before calling it.
+    write(\"hello\");",
+};
+pub const EXPECTED_CONDITION : ErrorCode = ErrorCode { code : "G0901", etype : ETypes::ConditionalError,
+    summary : "A condition is not a Bool",
+    explanation : "'if' and 'while' need a condition that evaluates to true or false.
+
+Example:
+    if (5) { write(1); };
+
+Fix: compare the value.
+    if (5 > 0) { write(1); };",
+};
+pub const CANNOT_CONVERT : ErrorCode = ErrorCode { code : "G1001", etype : ETypes::TypeError,
+    summary : "A string could not be converted to a number",
+    explanation : "to_int() and to_float() only accept strings that contain a number.
+
+Example:
+    to_int(\"twelve\");
+
+Fix: check the string first, or convert a string that holds digits.
+    to_int(\"12\");",
+};
+pub const WRONG_ARGUMENT_TYPE : ErrorCode = ErrorCode { code : "G1002", etype : ETypes::TypeError,
+    summary : "A standard function was given an argument of the wrong type",
+    explanation : "The message lists the types the function expects, in order.
+
+Example:
+    exit(\"1\");
+
+Fix: pass values of the expected types.
+    exit(1);",
+};
+pub const INDEX_NOT_INTEGER : ErrorCode = ErrorCode { code : "G1101", etype : ETypes::ListError,
+    summary : "A list index is not an Integer",
+    explanation : "Lists and strings are indexed by Integer positions, starting at 0.
+
+Example:
+    assign xs = [1, 2];
+    write(xs[\"0\"]);
+
+Fix: index with an Integer.
+    write(xs[0]);",
+};
+pub const INDEX_OUT_OF_BOUNDS : ErrorCode = ErrorCode { code : "G1102", etype : ETypes::ListError,
+    summary : "A list index is out of bounds",
+    explanation : "The index is negative, or not less than the length of the list.
+
+Example:
+    assign xs = [1, 2];
+    write(xs[2]);
+
+Fix: the last element is at length - 1.
+    write(xs[1]);",
+};
+pub const NOT_A_LIST : ErrorCode = ErrorCode { code : "G1103", etype : ETypes::ListError,
+    summary : "Something that is not a list was indexed",
+    explanation : "Only lists and strings can be indexed with '[]'.
+
+Example:
+    assign n = 5;
+    write(n[0]);
+
+Fix: index a list, or check which variable was meant.",
+};
+pub const UNDEFINED_PROPERTY : ErrorCode = ErrorCode { code : "G1201", etype : ETypes::BlueprintError,
+    summary : "A property does not exist on a blueprint",
+    explanation : "Objects only have the properties declared with 'prop' (and getters) in their blueprint.
+
+Example:
+    blueprint Car {
+        prop speed;
+        method create() { speed = 0; };
+    };
+    assign c = new Car();
+    write(c.sped);
+
+Fix: check the spelling, or declare the property in the blueprint.
+    write(c.speed);",
+};
+pub const UNDEFINED_METHOD : ErrorCode = ErrorCode { code : "G1202", etype : ETypes::BlueprintError,
+    summary : "A method does not exist on a blueprint",
+    explanation : "Objects only have the methods declared with 'method' in their blueprint. Strings have the methods of the '_string' blueprint in the standard library.
+
+Example:
+    assign s = \"abc\";
+    write(s.size());
+
+Fix: check the spelling or the blueprint.
+    write(s.length());",
+};
+pub const PRIVATE_MEMBER : ErrorCode = ErrorCode { code : "G1203", etype : ETypes::BlueprintError,
+    summary : "A private member was used from outside its blueprint",
+    explanation : "Members marked 'private' can only be used by the blueprint's own methods.
+
+Example:
+    blueprint Account {
+        private prop balance;
+        method create() { balance = 0; };
+    };
+    write(new Account().balance);
+
+Fix: add a method or getter that exposes what is needed.
+        get funds() { return balance; };",
+};
+pub const GETTER_WITHOUT_SETTER : ErrorCode = ErrorCode { code : "G1204", etype : ETypes::BlueprintError,
+    summary : "A property with a getter but no setter was assigned to",
+    explanation : "A property that only has a getter is read-only from outside the blueprint.
+
+Example:
+    blueprint Circle {
+        prop r;
+        method create() { r = 1; };
+        get area() { return r * r * 3; };
+    };
+    assign c = new Circle();
+    c.area = 10;
+
+Fix: assign to the underlying property, or declare a setter with 'set area(param v) { ... };'.",
+};
+pub const KEYWORD_AS_BLUEPRINT : ErrorCode = ErrorCode { code : "G1205", etype : ETypes::BlueprintError,
+    summary : "A keyword was used as a blueprint name",
+    explanation : "Keywords cannot be used as names.
+
+Example:
+    blueprint new { method create() {}; };
+
+Fix: pick another name.",
+};
+pub const UNDEFINED_BLUEPRINT : ErrorCode = ErrorCode { code : "G1206", etype : ETypes::BlueprintError,
+    summary : "A blueprint does not exist",
+    explanation : "'new' needs a blueprint defined in the current scope, or qualified with the name of the module it was imported from.
+
+Example:
+    import \"shapes.gsc\" -> shapes;
+    assign s = new Square(2);
+
+Fix: qualify blueprints from modules.
+    assign s = new shapes.Square(2);",
+};
+pub const MISSING_CONSTRUCTOR : ErrorCode = ErrorCode { code : "G1207", etype : ETypes::BlueprintError,
+    summary : "A blueprint has no 'create' method",
+    explanation : "'new' calls the blueprint's 'create' method, so every blueprint that is instantiated needs one.
+
+Example:
+    blueprint Point {
+        prop x;
+    };
+    assign p = new Point();
+
+Fix: add a constructor.
+    blueprint Point {
+        prop x;
+        method create() { x = 0; };
+    };",
+};
+pub const BLUEPRINT_EXISTS : ErrorCode = ErrorCode { code : "G1208", etype : ETypes::BlueprintError,
+    summary : "A blueprint is defined twice",
+    explanation : "Each blueprint name can only be defined once in a scope, including names brought in with import.
+
+Example:
+    blueprint Point { method create() {}; };
+    blueprint Point { method create() {}; };
+
+Fix: rename one of them, or import it under another name with 'as'.",
+};
+pub const UNDEFINED_VARIABLE : ErrorCode = ErrorCode { code : "G1301", etype : ETypes::IdentifierError,
+    summary : "A variable does not exist",
+    explanation : "No variable with this name is defined in the current scope or any parent scope. Variables only exist after their 'assign' has run.
+
+Example:
+    write(totl);
+
+Fix: check the spelling, or create the variable first.
+    assign total = 0;
+    write(total);",
+};
+pub const NOT_A_MODULE : ErrorCode = ErrorCode { code : "G1302", etype : ETypes::IdentifierError,
+    summary : "A qualified name does not refer to an imported module",
+    explanation : "In 'new mod.Thing()', 'mod' must be the name an import bound a module to.
+
+Example:
+    import \"shapes.gsc\" -> shapes;
+    assign s = new shape.Square(2);
+
+Fix: use the name given after '->'.
+    assign s = new shapes.Square(2);",
+};
+pub const MODULE_NOT_FOUND : ErrorCode = ErrorCode { code : "G1401", etype : ETypes::ImportError,
+    summary : "An imported file could not be found",
+    explanation : "Imports are looked up next to the importing file, then in the module search path (GSCRIPT_PATH, then 'std' next to the executable, then 'std' in the current directory).
+
+Example:
+    import \"util.gsc\" -> util;     (with no util.gsc next to the script)
+
+Fix: check the file name, or add its directory to GSCRIPT_PATH.",
+};
+pub const CIRCULAR_IMPORT : ErrorCode = ErrorCode { code : "G1402", etype : ETypes::ImportError,
+    summary : "Files import each other in a cycle",
+    explanation : "A module is run when it is first imported, so a file cannot import a file that is still being imported. The message shows the chain of imports.
+
+Example:
+    a.gsc:  import \"b.gsc\" -> b;
+    b.gsc:  import \"a.gsc\" -> a;
+
+Fix: move what both files need into a third file that they both import.",
+};
+pub const MODULE_HAS_ERRORS : ErrorCode = ErrorCode { code : "G1403", etype : ETypes::ImportError,
+    summary : "An imported file has syntax errors",
+    explanation : "A module is only run if it parses without errors. Its syntax errors are reported above this one.
+
+Fix: fix the errors in the imported file, 'gscript check' lists all of them.",
+};
+pub const NOT_EXPORTED : ErrorCode = ErrorCode { code : "G1404", etype : ETypes::ImportError,
+    summary : "A name is not exported by its module",
+    explanation : "Once a module exports anything, only its exported names can be used by files that import it.
+
+Example:
+    shapes.gsc:  export funct area(param s) { return s * s; };
+                 funct helper() { return 1; };
+    main.gsc:    import { helper } from \"shapes.gsc\";
+
+Fix: export the name from the module, or use one that is exported.",
+};
+pub const NOT_IN_MODULE : ErrorCode = ErrorCode { code : "G1405", etype : ETypes::ImportError,
+    summary : "An imported name does not exist in its module",
+    explanation : "Names listed in 'import { ... } from' must be variables, functions or blueprints defined at the top level of the module.
+
+Example:
+    import { aera } from \"shapes.gsc\";
+
+Fix: check the spelling.
+    import { area } from \"shapes.gsc\";",
+};
+
+///every error code, in order
+pub const ALL : [&ErrorCode; 50] = [
+    &INVALID_NUMBER, &EXPECTED_EXPRESSION, &UNMATCHED_BRACE, &EXPECTED_SEMICOLON, &EXPECTED_CLOSING_PAREN, &UNEXPECTED_TOKEN,
+    &INVALID_IMPORT, &INVALID_EXPORT, &EXPECTED_NAME, &INVALID_BLUEPRINT_MEMBER, &INVALID_ACCESSOR, &NOT_AN_OBJECT,
+    &UNREACHABLE_CODE,
+    &DIVIDE_BY_ZERO,
+    &CANNOT_READ_FILE, &MISSING_STD_FILE,
+    &UNRECOGNIZED_CHARACTER, &EXPECTED_TOKEN, &UNCLOSED_STRING,
+    &KEYWORD_AS_VARIABLE, &VARIABLE_EXISTS, &ASSIGN_TO_UNDEFINED, &UNUSED_VARIABLE, &SHADOWED_VARIABLE,
+    &KEYWORD_AS_FUNCTION, &FUNCTION_EXISTS, &UNUSED_PARAMETER,
+    &WRONG_ARGUMENT_COUNT, &UNDEFINED_FUNCTION,
+    &EXPECTED_CONDITION,
+    &CANNOT_CONVERT, &WRONG_ARGUMENT_TYPE,
+    &INDEX_NOT_INTEGER, &INDEX_OUT_OF_BOUNDS, &NOT_A_LIST,
+    &UNDEFINED_PROPERTY, &UNDEFINED_METHOD, &PRIVATE_MEMBER, &GETTER_WITHOUT_SETTER, &KEYWORD_AS_BLUEPRINT, &UNDEFINED_BLUEPRINT,
+    &MISSING_CONSTRUCTOR, &BLUEPRINT_EXISTS,
+    &UNDEFINED_VARIABLE, &NOT_A_MODULE,
+    &MODULE_NOT_FOUND, &CIRCULAR_IMPORT, &MODULE_HAS_ERRORS, &NOT_EXPORTED, &NOT_IN_MODULE,
+];
+///looks up a code like "G0104", ignoring case
+pub fn lookup(code : &str) -> Option<&'static ErrorCode> {
+    ALL.iter().copied().find(|c| c.code.eq_ignore_ascii_case(code))
+}
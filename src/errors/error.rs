@@ -1,7 +1,9 @@
 use colored::*;
+use super::codes::ErrorCode;
+use super::warnings::*;
 
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ETypes {
     SyntaxError,
     DivideByZeroError,
@@ -16,13 +18,9 @@ pub enum ETypes {
     ListError,
     BlueprintError,
     IdentifierError,
-    ImportError
-}
-#[derive(Debug)]
-pub enum EFlags {
-    NoFlag,
-    Semicolon,
-    CloseString
+    ImportError,
+    //not an actual error, exit() unwinds with this so that the caller can end the program
+    Exit(i32)
 }
 impl std::fmt::Display for ETypes {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -40,28 +38,90 @@ impl std::fmt::Display for ETypes {
             Self::ListError => write!(f, "ListError"),
             Self::BlueprintError => write!(f, "BlueprintError"),
             Self::IdentifierError => write!(f, "IdentifierError"),
-            Self::ImportError => write!(f, "ImportError")
+            Self::ImportError => write!(f, "ImportError"),
+            Self::Exit(code) => write!(f, "Exit({})", code)
         }
     }
 }
 pub struct GError {
     etype : ETypes,
-    message : String,
+    //boxed rather than a String to keep errors small, they are returned through every visit function
+    message : Box<str>,
     file : String,
     linecontents : String,
     line : usize,
     col : usize,
     col_end : usize,
+    //(function name, call site) of the calls being run when this happened, innermost last
+    trace : Box<[(String, ErrorInfo)]>,
+    //the stable code of this diagnostic, like G0104, see codes.rs
+    code : Option<&'static ErrorCode>,
 }
 impl GError {
     pub fn new(etype : ETypes, message : &str, file : String, linecontents : String, line : usize, col : usize, col_end : usize) -> GError {
         GError {
-            etype, message : message.to_string(), file, linecontents, line, col, col_end,
+            etype, message : message.into(), file, linecontents, line, col, col_end, trace : Box::new([]), code : None,
         }
     }
     pub fn new_from_tok(etype : ETypes, message : &str, einfo : ErrorInfo) -> GError {
         GError {
-            etype, message : message.to_string(), file : einfo.file, linecontents : einfo.linecontents, line : einfo.line, col : einfo.col, col_end : einfo.col_end 
+            etype, message : message.into(), file : einfo.file, linecontents : einfo.linecontents, line : einfo.line, col : einfo.col, col_end : einfo.col_end, trace : Box::new([]), code : None,
+        }
+    }
+    ///an error whose kind comes from its code
+    pub fn from_code(code : &'static ErrorCode, message : &str, einfo : ErrorInfo) -> GError {
+        let mut error = GError::new_from_tok(code.etype, message, einfo);
+        error.code = Some(code);
+        error
+    }
+    pub fn exit(code : i32, einfo : ErrorInfo) -> GError {
+        GError::new_from_tok(ETypes::Exit(code), format!("exit({})", code).as_str(), einfo)
+    }
+    ///records the call stack at the point this was raised, unless an inner call already did
+    pub fn with_trace(mut self, call_stack : &[(String, ErrorInfo)]) -> GError {
+        if self.trace.is_empty() {
+            self.trace = call_stack.into();
+        }
+        self
+    }
+    pub fn code(&self) -> Option<&'static str> {
+        self.code.map(|code| code.code)
+    }
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    pub fn col(&self) -> usize {
+        self.col
+    }
+    pub fn col_end(&self) -> usize {
+        self.col_end
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    ///the name of its kind, like "ListError"
+    pub fn kind(&self) -> String {
+        self.etype.to_string()
+    }
+    pub fn trace(&self) -> &[(String, ErrorInfo)] {
+        &self.trace
+    }
+    ///one line of JSON describing this error, for --error-format=json
+    pub fn to_json(&self) -> String {
+        let trace : Vec<String> = self.trace.iter().rev().map(|(name, site)| {
+            format!("{{\"function\":{},\"file\":{},\"line\":{},\"col\":{}}}",
+                json_string(name), json_string(&site.file), site.line, site.col)
+        }).collect();
+        let code = self.code().map_or(String::from("null"), json_string);
+        format!("{{\"severity\":\"error\",\"kind\":{},\"code\":{},\"message\":{},\"file\":{},\"line\":{},\"col\":{},\"col_end\":{},\"trace\":[{}]}}",
+            json_string(&self.etype.to_string()), code, json_string(&self.message), json_string(&self.file),
+            self.line, self.col, self.col_end, trace.join(","))
+    }
+    ///the exit code if this came from exit() instead of an actual error
+    pub fn exit_code(&self) -> Option<i32> {
+        match self.etype {
+            ETypes::Exit(code) => Some(code),
+            _ => None
         }
     }
     pub fn command_line(id: i32, message : &str) {
@@ -92,26 +152,94 @@ impl ErrorInfo {
         self.col_end = self.col + 1;
     }
 }
+//quotes and escapes 's' as a JSON string
+pub(crate) fn json_string(s : &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c)
+        }
+    }
+    out.push('"');
+    out
+}
+///how print_dump writes diagnostics to stderr
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorFormat {
+    //colored text for people to read
+    Human,
+    //one JSON object per line, for editors and other tools
+    Json
+}
+//how many calls print_dump shows under an error
+const MAX_TRACE : usize = 10;
+///a warning waiting to be printed by print_dump
+pub struct GWarning {
+    pub kind : Warning,
+    pub message : String,
+    pub einfo : ErrorInfo,
+}
+impl GWarning {
+    ///one line of JSON describing this warning, for --error-format=json
+    ///its kind is the name of its category, and its code is the one it is reported with when denied
+    pub fn to_json(&self) -> String {
+        format!("{{\"severity\":\"warning\",\"kind\":{},\"code\":{},\"message\":{},\"file\":{},\"line\":{},\"col\":{},\"col_end\":{}}}",
+            json_string(self.kind.name()), json_string(self.kind.code().code), json_string(&self.message), json_string(&self.einfo.file),
+            self.einfo.line, self.einfo.col, self.einfo.col_end)
+    }
+}
 pub struct ErrorStack {
     pub errors : Vec<GError>,
-    current_flag : EFlags
+    //kept until print_dump, so they aren't mixed in with the script's output
+    pub warnings : Vec<GWarning>,
+    pub config : WarningConfig,
+    pub format : ErrorFormat
 }
 impl ErrorStack {
     pub fn new() -> ErrorStack {
         ErrorStack {
             errors : Vec::new(),
-            current_flag: EFlags::NoFlag
+            warnings : Vec::new(),
+            config : WarningConfig::new(),
+            format : ErrorFormat::Human
         }
     }
-    pub fn warn(&self, einfo : ErrorInfo, warning : &str) {
+    ///records a warning, unless its kind is allowed, or as an error if its kind is denied
+    pub fn warn(&mut self, kind : Warning, einfo : ErrorInfo, message : &str) {
+        match self.config.level(kind) {
+            Level::Allow => {}
+            Level::Warn => self.warnings.push(GWarning { kind, message : message.to_string(), einfo }),
+            Level::Deny => {
+                let message = format!("{} (warning '{}' is denied)", message, kind.name());
+                self.errors.push(GError::from_code(kind.code(), message.as_str(), einfo));
+            }
+        }
+    }
+    ///forgets everything reported so far, once it has been printed
+    pub fn clear(&mut self) {
+        self.errors.clear();
+        self.warnings.clear();
+    }
+    fn print_warning(&self, warning : &GWarning) {
+        let einfo = &warning.einfo;
+        if self.format == ErrorFormat::Json {
+            eprintln!("{}", warning.to_json());
+            return;
+        }
         eprintln!("{}{}{}{}{}\n{}{}\n  --> {}",
             "in file ".yellow(),
             einfo.file.yellow(),
             ", at line ".yellow(),
             einfo.line.to_string().yellow(),
             ":".yellow(),
-            "warning: ".yellow().bold(),
-            warning.yellow(),
+            format!("warning[{}]: ", warning.kind.name()).yellow().bold(),
+            warning.message.yellow(),
             einfo.linecontents,
         );
         for _i in 0..einfo.col+5 {
@@ -120,11 +248,17 @@ impl ErrorStack {
         for _i in 0..einfo.col_end-einfo.col {
             eprint!("{}","^".yellow().bold());
         }
-        eprint!("\n(flag {})", format!("{:#?}", self.current_flag).yellow());
         eprintln!("{}","\n-----------------------------------------".yellow());
     }
-    pub fn flag(&mut self, f: EFlags) {
-        self.current_flag = f;
+    ///like "2 warnings and 1 error", or None if nothing was reported
+    pub fn summary(&self) -> Option<String> {
+        let plural = |n : usize, what : &str| format!("{} {}{}", n, what, if n == 1 { "" } else { "s" });
+        match (self.warnings.len(), self.errors.len()) {
+            (0, 0) => None,
+            (w, 0) => Some(plural(w, "warning")),
+            (0, e) => Some(plural(e, "error")),
+            (w, e) => Some(format!("{} and {}", plural(w, "warning"), plural(e, "error")))
+        }
     }
     fn line_whitespace(&self, num : usize, empty : bool) -> String {
         let width = num.to_string().chars().count();
@@ -137,13 +271,23 @@ impl ErrorStack {
     fn line_whitespace_count(&self, num : usize) -> usize {
         num.to_string().chars().count()+2
     }
+    ///prints the warnings, then the errors, then how many of each there were
     pub fn print_dump(&self) {
+        for warning in &self.warnings {
+            self.print_warning(warning);
+        }
+        if self.format == ErrorFormat::Json {
+            for error in &self.errors {
+                eprintln!("{}", error.to_json());
+            }
+            return;
+        }
         if self.errors.len() != 0 {
             eprintln!("{}","-----------------------------------------".red());
         }
         for error in &self.errors {
             eprintln!(
-                "{}{}{}{}{}{} \n{}{}{}{}\n{}| \n{}| --> {}",
+                "{}{}{}{}{}{} \n{}{}{}{}{}\n{}| \n{}| --> {}",
                 "gscript ".red().italic(),
                 error.file.truecolor(186, 149, 48), 
                 ": Line ".red(),
@@ -151,7 +295,9 @@ impl ErrorStack {
                 ", Char ".red(),
                 error.col.to_string().red(), 
                 self.line_whitespace(error.line, true), 
-                error.etype.to_string().red().bold(), ": ".red().bold(), 
+                error.etype.to_string().red().bold(),
+                error.code().map(|code| format!("[{}]", code)).unwrap_or_default().red().bold(),
+                ": ".red().bold(), 
                 error.message.red(),self.line_whitespace(error.line, true), 
                 self.line_whitespace(error.line, false), 
                 error.linecontents.bold(), 
@@ -161,11 +307,36 @@ impl ErrorStack {
                 eprint!("{}","^".red().bold());
             }
             eprintln!();
+            self.print_trace(error);
             eprintln!("{}","-----------------------------------------".red());
         }
+        if let Some(summary) = self.summary() {
+            let summary = format!("gscript: {}", summary);
+            eprintln!("{}", if self.errors.is_empty() { summary.yellow().bold() } else { summary.red().bold() });
+        }
     } 
-    pub fn terminate_gs(&self) {
-        self.print_dump();
-        std::process::exit(1);
+    //lists the calls that led to a runtime error, most recent first
+    //deep recursion only shows the innermost calls
+    fn print_trace(&self, error : &GError) {
+        if error.trace.is_empty() {
+            return;
+        }
+        eprintln!("{}{}", self.line_whitespace(error.line, true), "call stack (most recent call first):".red());
+        for (name, site) in error.trace.iter().rev().take(MAX_TRACE) {
+            eprintln!("{}{}{}{}{}{}{}{}",
+                self.line_whitespace(error.line, true),
+                "  in ".red(),
+                name.red().bold(),
+                ", called at ".red(),
+                site.file.truecolor(186, 149, 48),
+                ": Line ".red(),
+                site.line.to_string().red(),
+                format!(" --> {}", site.linecontents.trim()).bold(),
+            );
+        }
+        if error.trace.len() > MAX_TRACE {
+            eprintln!("{}{}", self.line_whitespace(error.line, true),
+                format!("  ... and {} more", error.trace.len() - MAX_TRACE).red());
+        }
     }
 }
@@ -0,0 +1,53 @@
+//"did you mean" hints for names that don't exist, based on edit distance
+
+///number of single character insertions, deletions, substitutions and swaps of two
+///neighbouring characters needed to turn 'a' into 'b'
+pub fn edit_distance(a : &str, b : &str) -> usize {
+    let a : Vec<char> = a.chars().collect();
+    let b : Vec<char> = b.chars().collect();
+    //d[i][j] is the distance between the first i characters of a and the first j characters of b
+    let mut d = vec![vec![0; b.len()+1]; a.len()+1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    d[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i-1] == b[j-1] { 0 } else { 1 };
+            d[i][j] = (d[i-1][j]+1).min(d[i][j-1]+1).min(d[i-1][j-1]+cost);
+            if i > 1 && j > 1 && a[i-1] == b[j-2] && a[i-2] == b[j-1] {
+                d[i][j] = d[i][j].min(d[i-2][j-2]+1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+///the candidate closest to 'name', if it is close enough to likely be what was meant
+///differences in case are counted as a single edit
+pub fn closest<'a>(name : &str, candidates : impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let lower = name.to_lowercase();
+    //roughly one typo for every three characters
+    let max = name.chars().count().div_ceil(3);
+    let mut best : Option<(usize, &str)> = None;
+    for candidate in candidates {
+        if candidate == name {
+            continue;
+        }
+        let distance = edit_distance(&lower, &candidate.to_lowercase()).max(1);
+        if distance > max || distance >= name.chars().count() {
+            continue;
+        }
+        //ties go to the alphabetically first name, so the hint doesn't depend on hash map order
+        if best.is_none_or(|(d, b)| distance < d || (distance == d && candidate < b)) {
+            best = Some((distance, candidate));
+        }
+    }
+    best.map(|(_, candidate)| candidate)
+}
+///appends a hint to 'message' naming the closest candidate to 'name', if there is one
+pub fn with_suggestion<'a>(message : String, name : &str, candidates : impl IntoIterator<Item = &'a str>) -> String {
+    match closest(name, candidates) {
+        Some(candidate) => format!("{}. Did you mean '{}'?", message, candidate),
+        None => message
+    }
+}
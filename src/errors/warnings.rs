@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use super::error::ETypes;
+use super::codes::*;
+
+//warnings point out code that runs but is probably a mistake
+//each kind has a name, so it can be allowed, kept as a warning or denied in gscript.cfg or with --allow, --warn and --deny
+
+///the project config file, looked for in the script's directory and then each parent directory
+pub const CONFIG_FILE : &str = "gscript.cfg";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Warning {
+    //a statement that looks finished but has no ';' after it
+    MissingSemicolon,
+    //a string that runs past the end of its line
+    UnclosedString,
+    //the rest are only looked for by 'gscript lint'
+    //a variable that is given a value but never read
+    UnusedVariable,
+    //a parameter that the function never reads
+    UnusedParameter,
+    //a statement after a 'return' or 'break' in the same block
+    UnreachableCode,
+    //a blueprint without a 'create' method, so it can't be instantiated
+    MissingConstructor,
+    //a variable or parameter with the same name as a variable in an enclosing scope
+    ShadowedVariable,
+}
+impl Warning {
+    pub const ALL : [Warning; 7] = [
+        Warning::MissingSemicolon, Warning::UnclosedString, Warning::UnusedVariable, Warning::UnusedParameter,
+        Warning::UnreachableCode, Warning::MissingConstructor, Warning::ShadowedVariable,
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MissingSemicolon => "missing-semicolon",
+            Self::UnclosedString => "unclosed-string",
+            Self::UnusedVariable => "unused-variable",
+            Self::UnusedParameter => "unused-parameter",
+            Self::UnreachableCode => "unreachable-code",
+            Self::MissingConstructor => "missing-constructor",
+            Self::ShadowedVariable => "shadowed-variable",
+        }
+    }
+    pub fn from_name(name : &str) -> Option<Warning> {
+        Warning::ALL.into_iter().find(|w| w.name() == name)
+    }
+    ///the code of the error this becomes when it is denied
+    pub fn code(&self) -> &'static ErrorCode {
+        match self {
+            Self::MissingSemicolon => &EXPECTED_SEMICOLON,
+            Self::UnclosedString => &UNCLOSED_STRING,
+            Self::UnusedVariable => &UNUSED_VARIABLE,
+            Self::UnusedParameter => &UNUSED_PARAMETER,
+            Self::UnreachableCode => &UNREACHABLE_CODE,
+            Self::MissingConstructor => &MISSING_CONSTRUCTOR,
+            Self::ShadowedVariable => &SHADOWED_VARIABLE,
+        }
+    }
+    ///the kind of error this becomes when it is denied
+    pub fn etype(&self) -> ETypes {
+        self.code().etype
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Level {
+    //not reported at all
+    Allow,
+    //reported, but the script still runs
+    Warn,
+    //reported as an error, so the script doesn't run
+    Deny,
+}
+impl Level {
+    pub fn from_name(name : &str) -> Option<Level> {
+        match name {
+            "allow" => Some(Level::Allow),
+            "warn" => Some(Level::Warn),
+            "deny" => Some(Level::Deny),
+            _ => None
+        }
+    }
+}
+
+///the level of every kind of warning, all of them are 'warn' unless changed
+#[derive(Clone, Debug, Default)]
+pub struct WarningConfig {
+    levels : HashMap<Warning, Level>,
+}
+impl WarningConfig {
+    pub fn new() -> WarningConfig {
+        WarningConfig { levels : HashMap::new() }
+    }
+    pub fn level(&self, warning : Warning) -> Level {
+        self.levels.get(&warning).copied().unwrap_or(Level::Warn)
+    }
+    ///sets the level of the warning called 'name', or of every warning if it is 'all'
+    pub fn set(&mut self, name : &str, level : Level) -> Result<(), String> {
+        if name == "all" {
+            for warning in Warning::ALL {
+                self.levels.insert(warning, level);
+            }
+            return Ok(());
+        }
+        match Warning::from_name(name) {
+            Some(warning) => {
+                self.levels.insert(warning, level);
+                Ok(())
+            }
+            None => {
+                let names : Vec<&str> = Warning::ALL.iter().map(|w| w.name()).collect();
+                Err(format!("Unknown warning '{}', expected 'all' or one of: {}", name, names.join(", ")))
+            }
+        }
+    }
+    ///reads 'name = level' lines, later lines win and '#' starts a comment
+    ///  # semicolons are required in this project
+    ///  missing-semicolon = deny
+    pub fn parse(&mut self, source : &str, filename : &str) -> Result<(), String> {
+        for (i, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((name, level)) = line.split_once('=') else {
+                return Err(format!("{}: Line {}: Expected 'warning = level'", filename, i+1));
+            };
+            let Some(level) = Level::from_name(level.trim()) else {
+                return Err(format!("{}: Line {}: Unknown level '{}', expected 'allow', 'warn' or 'deny'", filename, i+1, level.trim()));
+            };
+            self.set(name.trim(), level).map_err(|e| format!("{}: Line {}: {}", filename, i+1, e))?;
+        }
+        Ok(())
+    }
+    ///the closest config file to 'dir', checking 'dir' and then each of its parents
+    pub fn find(dir : &Path) -> Option<PathBuf> {
+        dir.ancestors().map(|d| d.join(CONFIG_FILE)).find(|p| p.is_file())
+    }
+    ///reads the config file closest to 'dir', if there is one
+    pub fn load(&mut self, dir : &Path) -> Result<(), String> {
+        let Some(path) = WarningConfig::find(dir) else {
+            return Ok(());
+        };
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Could not read '{}'\n  {}", path.display(), e))?;
+        self.parse(&source, &path.display().to_string())
+    }
+}
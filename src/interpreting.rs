@@ -1,3 +1,8 @@
 pub mod scope;
 pub mod visitor;
-pub mod stdfunc;
\ No newline at end of file
+pub mod stdfunc;
+pub mod modules;
+pub mod repl;
+pub mod lint;
+pub mod analysis;
+pub mod lsp;
\ No newline at end of file
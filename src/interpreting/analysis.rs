@@ -0,0 +1,526 @@
+use crate::errors::error::*;
+use crate::modules::STD_MODULES;
+use crate::parsing::lexer::Lexer;
+use crate::parsing::token::*;
+use crate::visitor::BUILTIN_FUNCTIONS;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+//the analysis behind 'gscript lsp', which finds where each name in a script is defined and used without running it
+//it only needs the tokens, so it still works while a script is half written and doesn't parse
+//names follow the linter's scoping: functions, methods, loops and blueprints get their own scope, if blocks don't
+
+const KEYWORDS : [&str; 22] = [
+    "assign", "funct", "param", "return", "if", "else", "while", "break", "blueprint", "new", "prop", "method",
+    "get", "set", "static", "private", "self", "import", "export", "from", "true", "false",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SymbolKind {
+    Variable,
+    Parameter,
+    Function,
+    Blueprint,
+    Property,
+    //methods, getters and setters
+    Method,
+    //a module bound by 'import "file.gsc" -> name;'
+    Module,
+    //a name bound by 'import { name } from "file.gsc";', which could be a variable, function or blueprint
+    Imported,
+    Builtin,
+    Keyword,
+}
+#[derive(Clone, Debug)]
+pub struct Symbol {
+    pub name : String,
+    pub kind : SymbolKind,
+    //where the name is written in its definition
+    pub einfo : ErrorInfo,
+    //the parameters of a function or method, or of a blueprint's 'create'
+    pub params : Vec<String>,
+    //the blueprint a property or method belongs to
+    pub blueprint : Option<String>,
+    pub private : bool,
+    //the blueprint of a variable's value when it is known, like 'Car' for 'assign c = new Car();' or '_string' for a string
+    pub value_blueprint : Option<String>,
+    //the file a module or imported name comes from
+    pub from : Option<String>,
+    //the keywords it was defined with, like "static method"
+    definition : String,
+    scope : usize,
+}
+impl Symbol {
+    ///how the symbol is defined, like "funct add(param a, param b)", which is shown when hovering over it
+    pub fn signature(&self) -> String {
+        let params : Vec<String> = self.params.iter().map(|p| format!("param {}", p)).collect();
+        let params = params.join(", ");
+        let from = self.from.as_deref().unwrap_or_default();
+        match self.kind {
+            SymbolKind::Function | SymbolKind::Method => format!("{} {}({})", self.definition, self.name, params),
+            SymbolKind::Blueprint => format!("blueprint {}\nnew {}({})", self.name, self.name, params),
+            SymbolKind::Module => format!("import \"{}\" -> {}", from, self.name),
+            SymbolKind::Imported => format!("import {{ {} }} from \"{}\"", self.name, from),
+            SymbolKind::Builtin | SymbolKind::Keyword => self.definition.clone(),
+            _ => format!("{} {}", self.definition, self.name),
+        }
+    }
+    fn is_member(&self) -> bool {
+        matches!(self.kind, SymbolKind::Property | SymbolKind::Method)
+    }
+}
+#[derive(Clone, Copy, PartialEq)]
+enum ScopeKind {
+    Global,
+    Function,
+    Loop,
+    Blueprint,
+    //an if or else block, whose names belong to the scope around it
+    If,
+}
+struct AnalysisScope {
+    kind : ScopeKind,
+    parent : Option<usize>,
+    //the blueprint whose members are defined in this scope
+    blueprint : Option<String>,
+}
+//a scope that starts at the next '{', with the parameters that are defined in it
+struct PendingScope {
+    kind : ScopeKind,
+    blueprint : Option<String>,
+    params : Vec<usize>,
+}
+pub struct Analysis {
+    filename : String,
+    pub symbols : Vec<Symbol>,
+    //each name written in the script, with the symbol it refers to
+    pub references : Vec<(ErrorInfo, usize)>,
+    scopes : Vec<AnalysisScope>,
+    tokens : Vec<Token>,
+    //the scope each token is in
+    token_scopes : Vec<usize>,
+}
+impl Analysis {
+    ///analyzes a script along with the standard library, whose blueprints like '_string' are visible everywhere
+    pub fn analyze(filename : &str, source : &str) -> Analysis {
+        let mut analysis = Analysis {
+            filename : filename.to_string(),
+            symbols : Vec::new(),
+            references : Vec::new(),
+            scopes : vec![AnalysisScope { kind : ScopeKind::Global, parent : None, blueprint : None }],
+            tokens : Vec::new(),
+            token_scopes : Vec::new(),
+        };
+        for (name, std_source) in STD_MODULES {
+            let tokens = lex(&format!("std/{}", name), std_source);
+            analysis.walk(&tokens);
+        }
+        let tokens = lex(filename, source);
+        let (token_scopes, definitions) = analysis.walk(&tokens);
+        analysis.tokens = tokens;
+        analysis.token_scopes = token_scopes;
+        analysis.resolve_references(definitions);
+        analysis
+    }
+    ///the symbol for the name at a position, or None if there is no name there or it isn't defined anywhere
+    ///lines and columns start at 1, like in ErrorInfo
+    pub fn symbol_at(&self, line : usize, col : usize) -> Option<&Symbol> {
+        self.references.iter()
+            .find(|(einfo, _)| einfo.line == line && einfo.col <= col && col <= einfo.col_end)
+            .map(|(_, symbol)| &self.symbols[*symbol])
+    }
+    ///the text shown when hovering over a position, which is the signature of a symbol or builtin function
+    pub fn hover(&self, line : usize, col : usize) -> Option<(String, ErrorInfo)> {
+        if let Some((einfo, symbol)) = self.references.iter().find(|(einfo, _)| einfo.line == line && einfo.col <= col && col <= einfo.col_end) {
+            return Some((self.symbols[*symbol].signature(), einfo.clone()));
+        }
+        let token = self.tokens.iter().find(|t| t.einfo.line == line && t.einfo.col <= col && col <= t.einfo.col_end)?;
+        match &token.kind {
+            TokenType::ID(name) => builtin_signature(name).map(|s| (s.to_string(), token.einfo.clone())),
+            _ => None
+        }
+    }
+    ///the names that could be written at a position, filtered by the part of a name just before it
+    ///after a '.' these are the members of the blueprint the value before it is known to be, or of every blueprint
+    pub fn completions(&self, line : usize, col : usize) -> Vec<Symbol> {
+        //the last token before the position, not counting the end of the file
+        let last = self.tokens.iter().rposition(|t| t.kind != TokenType::EOF && (t.einfo.line, t.einfo.col_end) <= (line, col));
+        let (prefix, context) = match last.map(|i| (i, &self.tokens[i])) {
+            Some((i, Token { kind : TokenType::ID(name), einfo })) if einfo.line == line && einfo.col_end == col => (name.as_str(), i.checked_sub(1)),
+            _ => ("", last)
+        };
+        let scope = last.map_or(0, |i| self.token_scopes[i]);
+        let mut completions : Vec<Symbol> = match context.map(|i| (i, &self.tokens[i].kind)) {
+            Some((i, TokenType::DOT)) => self.member_completions(i),
+            Some((_, TokenType::ID(word))) if word == "new" => {
+                self.visible(scope).into_iter().filter(|s| s.kind == SymbolKind::Blueprint).collect()
+            }
+            _ => {
+                let mut completions = self.visible(scope);
+                completions.extend(BUILTIN_FUNCTIONS.iter().map(|name| builtin(name, SymbolKind::Builtin, builtin_signature(name).unwrap_or(name))));
+                completions.extend(KEYWORDS.iter().map(|word| builtin(word, SymbolKind::Keyword, word)));
+                completions
+            }
+        };
+        completions.retain(|s| s.name.starts_with(prefix));
+        completions
+    }
+    //the members that can follow the '.' at tokens[dot]
+    fn member_completions(&self, dot : usize) -> Vec<Symbol> {
+        let Some(receiver) = dot.checked_sub(1) else {
+            return Vec::new();
+        };
+        let is_self = self.tokens[receiver].kind == TokenType::ID("self".to_string());
+        let blueprint = self.receiver_blueprint(receiver);
+        //the names in another file aren't known
+        if blueprint.is_none() && self.receiver_symbol(receiver).is_some_and(|s| matches!(s.kind, SymbolKind::Module | SymbolKind::Imported)) {
+            return Vec::new();
+        }
+        let mut members : Vec<Symbol> = Vec::new();
+        //'create' can't be called on an object, and private members are only available through 'self'
+        for symbol in self.symbols.iter().filter(|s| s.is_member() && s.name != "create" && (is_self || !s.private)) {
+            let matches = match &blueprint {
+                Some(blueprint) => symbol.blueprint.as_ref() == Some(blueprint),
+                None => !members.iter().any(|m| m.name == symbol.name)
+            };
+            if matches {
+                members.push(symbol.clone());
+            }
+        }
+        members
+    }
+    //the symbols visible from a scope, innermost first, leaving out the internal blueprints of the standard library
+    fn visible(&self, scope : usize) -> Vec<Symbol> {
+        let mut visible : Vec<Symbol> = Vec::new();
+        let mut scope = Some(scope);
+        while let Some(s) = scope {
+            for symbol in self.symbols.iter().filter(|sym| sym.scope == s) {
+                let internal = symbol.einfo.file != self.filename && symbol.name.starts_with('_');
+                if !internal && !visible.iter().any(|v| v.name == symbol.name) {
+                    visible.push(symbol.clone());
+                }
+            }
+            scope = self.scopes[s].parent;
+        }
+        visible
+    }
+    fn add_scope(&mut self, kind : ScopeKind, parent : usize, blueprint : Option<String>) -> usize {
+        self.scopes.push(AnalysisScope { kind, parent : Some(parent), blueprint });
+        self.scopes.len() - 1
+    }
+    //if blocks don't have scopes of their own, so names defined in them go in the scope around them
+    fn definition_scope(&self, mut scope : usize) -> usize {
+        while let (ScopeKind::If, Some(parent)) = (self.scopes[scope].kind, self.scopes[scope].parent) {
+            scope = parent;
+        }
+        scope
+    }
+    //the blueprint that a method in this scope belongs to
+    fn enclosing_blueprint(&self, scope : usize) -> Option<String> {
+        let mut scope = Some(scope);
+        while let Some(s) = scope {
+            if self.scopes[s].kind == ScopeKind::Blueprint {
+                return self.scopes[s].blueprint.clone();
+            }
+            scope = self.scopes[s].parent;
+        }
+        None
+    }
+    fn define(&mut self, name : &str, kind : SymbolKind, definition : &str, einfo : &ErrorInfo, scope : usize) -> usize {
+        self.symbols.push(Symbol {
+            name : name.to_string(),
+            kind,
+            einfo : einfo.clone(),
+            params : Vec::new(),
+            blueprint : self.scopes[scope].blueprint.clone().filter(|_| matches!(kind, SymbolKind::Property | SymbolKind::Method)),
+            private : definition.split(' ').any(|word| word == "private"),
+            value_blueprint : None,
+            from : None,
+            definition : definition.to_string(),
+            scope,
+        });
+        self.symbols.len() - 1
+    }
+    //finds the definitions in a file, returning the scope of each token and the (token, symbol) pairs of the names being defined
+    fn walk(&mut self, tokens : &[Token]) -> (Vec<usize>, Vec<(usize, usize)>) {
+        let id = |i : usize| match tokens.get(i).map(|t| &t.kind) {
+            Some(TokenType::ID(name)) if !KEYWORDS.contains(&name.as_str()) => Some(name.as_str()),
+            _ => None
+        };
+        let word = |i : usize| match tokens.get(i).map(|t| &t.kind) {
+            Some(TokenType::ID(name)) => name.as_str(),
+            _ => ""
+        };
+        let mut stack = vec![0];
+        //whether each open '{' started a scope, rather than the names of an import
+        let mut braces = Vec::new();
+        let mut pending : Option<PendingScope> = None;
+        let mut token_scopes = Vec::with_capacity(tokens.len());
+        let mut definitions = Vec::new();
+        for (i, token) in tokens.iter().enumerate() {
+            let current = *stack.last().unwrap();
+            let in_blueprint = self.scopes[current].kind == ScopeKind::Blueprint;
+            match &token.kind {
+                TokenType::ID(keyword) => match (keyword.as_str(), id(i+1)) {
+                    //members can only be defined directly inside a blueprint
+                    ("prop" | "method" | "get" | "set", _) if !in_blueprint => {}
+                    ("assign" | "prop", Some(name)) => {
+                        let (kind, scope) = if keyword == "prop" {
+                            (SymbolKind::Property, current)
+                        } else {
+                            (SymbolKind::Variable, self.definition_scope(current))
+                        };
+                        let symbol = self.define(name, kind, &modifiers(tokens, i), &tokens[i+1].einfo, scope);
+                        if tokens.get(i+2).is_some_and(|t| t.kind == TokenType::EQL) {
+                            self.symbols[symbol].value_blueprint = self.value_blueprint(tokens, i+3, scope);
+                        }
+                        definitions.push((i+1, symbol));
+                    }
+                    ("funct" | "method" | "get" | "set", Some(name)) => {
+                        let (kind, scope) = if keyword == "funct" {
+                            (SymbolKind::Function, self.definition_scope(current))
+                        } else {
+                            (SymbolKind::Method, current)
+                        };
+                        let symbol = self.define(name, kind, &modifiers(tokens, i), &tokens[i+1].einfo, scope);
+                        definitions.push((i+1, symbol));
+                        //'(param a, param b)'
+                        let params : Vec<usize> = (i+2..tokens.len())
+                            .take_while(|&j| !matches!(tokens[j].kind, TokenType::RPR | TokenType::LBR | TokenType::SEMI | TokenType::EOF))
+                            .filter(|&j| word(j) == "param" && id(j+1).is_some())
+                            .map(|j| j+1)
+                            .collect();
+                        self.symbols[symbol].params = params.iter().map(|&j| word(j).to_string()).collect();
+                        pending = Some(PendingScope { kind : ScopeKind::Function, blueprint : None, params });
+                    }
+                    ("blueprint", Some(name)) => {
+                        let symbol = self.define(name, SymbolKind::Blueprint, "blueprint", &tokens[i+1].einfo, self.definition_scope(current));
+                        definitions.push((i+1, symbol));
+                        pending = Some(PendingScope { kind : ScopeKind::Blueprint, blueprint : Some(name.to_string()), params : Vec::new() });
+                    }
+                    ("while", _) => pending = Some(PendingScope { kind : ScopeKind::Loop, blueprint : None, params : Vec::new() }),
+                    ("if", _) | ("else", _) => pending = Some(PendingScope { kind : ScopeKind::If, blueprint : None, params : Vec::new() }),
+                    ("import", _) => definitions.extend(self.define_import(tokens, i, self.definition_scope(current))),
+                    _ => {}
+                },
+                TokenType::LBR if matches!(word(i.wrapping_sub(1)), "import" | "export") => braces.push(false),
+                TokenType::LBR => {
+                    let scope = pending.take().unwrap_or(PendingScope { kind : ScopeKind::If, blueprint : None, params : Vec::new() });
+                    let blueprint = scope.blueprint.or_else(|| self.scopes[current].blueprint.clone());
+                    let new_scope = self.add_scope(scope.kind, current, blueprint);
+                    for param in scope.params {
+                        let symbol = self.define(word(param), SymbolKind::Parameter, "param", &tokens[param].einfo, new_scope);
+                        definitions.push((param, symbol));
+                    }
+                    braces.push(true);
+                    stack.push(new_scope);
+                }
+                TokenType::RBR => {
+                    //the '}' after an import's names doesn't close a scope
+                    let closes_scope = braces.pop() == Some(true);
+                    if closes_scope && stack.len() > 1 {
+                        stack.pop();
+                    }
+                }
+                _ => {}
+            }
+            token_scopes.push(*stack.last().unwrap());
+        }
+        //a blueprint is created with the parameters of its 'create' method
+        for b in 0..self.symbols.len() {
+            if self.symbols[b].kind == SymbolKind::Blueprint && self.symbols[b].params.is_empty() {
+                let create = self.symbols.iter().find(|s| s.kind == SymbolKind::Method && s.name == "create" && s.blueprint.as_ref() == Some(&self.symbols[b].name));
+                self.symbols[b].params = create.map(|c| c.params.clone()).unwrap_or_default();
+            }
+        }
+        (token_scopes, definitions)
+    }
+    //'import "file.gsc" -> name;' or 'import { name, other as alias } from "file.gsc";' at tokens[i]
+    fn define_import(&mut self, tokens : &[Token], i : usize, scope : usize) -> Vec<(usize, usize)> {
+        let mut definitions = Vec::new();
+        match tokens.get(i+1).map(|t| &t.kind) {
+            Some(TokenType::STRING(file)) => {
+                if let (Some(TokenType::RARW), Some(TokenType::ID(name))) = (tokens.get(i+2).map(|t| &t.kind), tokens.get(i+3).map(|t| &t.kind)) {
+                    let symbol = self.define(name, SymbolKind::Module, "import", &tokens[i+3].einfo, scope);
+                    self.symbols[symbol].from = Some(file.clone());
+                    definitions.push((i+3, symbol));
+                }
+            }
+            Some(TokenType::LBR) => {
+                let end = (i+2..tokens.len()).find(|&j| matches!(tokens[j].kind, TokenType::RBR | TokenType::SEMI | TokenType::EOF)).unwrap_or(tokens.len());
+                let file = match (tokens.get(end+1).map(|t| &t.kind), tokens.get(end+2).map(|t| &t.kind)) {
+                    (Some(TokenType::ID(from)), Some(TokenType::STRING(file))) if from == "from" => Some(file.clone()),
+                    _ => None
+                };
+                //each name is followed by a ',' or 'as alias'
+                let mut j = i+2;
+                while j < end {
+                    if let TokenType::ID(name) = &tokens[j].kind {
+                        let renamed = matches!(&tokens.get(j+1).map(|t| &t.kind), Some(TokenType::ID(word)) if word == "as") && j+2 < end;
+                        let (alias, at) = match &tokens[if renamed { j+2 } else { j }].kind {
+                            TokenType::ID(alias) => (alias.clone(), if renamed { j+2 } else { j }),
+                            _ => (name.clone(), j)
+                        };
+                        let symbol = self.define(&alias, SymbolKind::Imported, "import", &tokens[at].einfo, scope);
+                        self.symbols[symbol].from = file.clone();
+                        definitions.push((j, symbol));
+                        if renamed {
+                            definitions.push((j+2, symbol));
+                            j += 2;
+                        }
+                    }
+                    j += 1;
+                }
+            }
+            _ => {}
+        }
+        definitions
+    }
+    //the blueprint of the value starting at tokens[i], if it is 'new Thing(...)', a string or a variable whose blueprint is known
+    fn value_blueprint(&self, tokens : &[Token], i : usize, scope : usize) -> Option<String> {
+        match &tokens.get(i)?.kind {
+            TokenType::STRING(_) => Some("_string".to_string()),
+            //'new Thing()' or 'new module.Thing()'
+            TokenType::ID(word) if word == "new" => tokens[i+1..].iter()
+                .take_while(|t| matches!(t.kind, TokenType::ID(_) | TokenType::DOT))
+                .filter_map(|t| match &t.kind {
+                    TokenType::ID(name) => Some(name.clone()),
+                    _ => None
+                })
+                .last(),
+            TokenType::ID(name) if tokens.get(i+1).is_some_and(|t| t.kind == TokenType::SEMI) => {
+                self.resolve(name, scope, &tokens[i].einfo).and_then(|s| self.symbols[s].value_blueprint.clone())
+            }
+            _ => None
+        }
+    }
+    //links each name in the script to its symbol, once every definition has been found
+    fn resolve_references(&mut self, definitions : Vec<(usize, usize)>) {
+        let definitions : HashMap<usize, usize> = definitions.into_iter().collect();
+        let mut references = Vec::new();
+        for (i, token) in self.tokens.iter().enumerate() {
+            let TokenType::ID(name) = &token.kind else {
+                continue;
+            };
+            let symbol = match definitions.get(&i) {
+                Some(symbol) => Some(*symbol),
+                None if i > 0 && self.tokens[i-1].kind == TokenType::DOT => self.resolve_member(i),
+                None => self.resolve(name, self.token_scopes[i], &token.einfo),
+            };
+            if let Some(symbol) = symbol {
+                references.push((token.einfo.clone(), symbol));
+            }
+        }
+        self.references = references;
+    }
+    //the symbol a name refers to from a scope, preferring the last definition before it in the innermost scope that has one
+    //a definition after it is used if there is none before, as functions can use names defined after them
+    fn resolve(&self, name : &str, scope : usize, einfo : &ErrorInfo) -> Option<usize> {
+        let mut scope = Some(scope);
+        while let Some(s) = scope {
+            let mut candidates = self.symbols.iter().enumerate().filter(|(_, sym)| sym.scope == s && sym.name == name).peekable();
+            let first = candidates.peek().map(|(i, _)| *i);
+            let before = candidates
+                .rfind(|(_, sym)| sym.einfo.file != einfo.file || (sym.einfo.line, sym.einfo.col) <= (einfo.line, einfo.col))
+                .map(|(i, _)| i);
+            if let Some(found) = before.or(first) {
+                return Some(found);
+            }
+            scope = self.scopes[s].parent;
+        }
+        None
+    }
+    //the member named by tokens[i], which comes after a '.'
+    fn resolve_member(&self, i : usize) -> Option<usize> {
+        let TokenType::ID(name) = &self.tokens[i].kind else {
+            return None;
+        };
+        let mut members = self.symbols.iter().enumerate().filter(|(_, s)| s.is_member() && &s.name == name);
+        match self.receiver_blueprint(i.checked_sub(2)?) {
+            Some(blueprint) => members.find(|(_, s)| s.blueprint.as_ref() == Some(&blueprint)).map(|(m, _)| m),
+            //without knowing the blueprint, only a name that a single blueprint has can be found
+            None => {
+                let found = members.next()?.0;
+                members.next().is_none().then_some(found)
+            }
+        }
+    }
+    //the symbol of the value before a '.', if it is a name
+    fn receiver_symbol(&self, i : usize) -> Option<&Symbol> {
+        let TokenType::ID(name) = &self.tokens[i].kind else {
+            return None;
+        };
+        let symbol = if i > 0 && self.tokens[i-1].kind == TokenType::DOT {
+            self.resolve_member(i)
+        } else {
+            self.resolve(name, self.token_scopes[i], &self.tokens[i].einfo)
+        };
+        symbol.map(|s| &self.symbols[s])
+    }
+    //the blueprint of the value at tokens[i], which comes before a '.'
+    fn receiver_blueprint(&self, i : usize) -> Option<String> {
+        match &self.tokens[i].kind {
+            TokenType::STRING(_) => Some("_string".to_string()),
+            TokenType::ID(name) if name == "self" => self.enclosing_blueprint(self.token_scopes[i]),
+            TokenType::ID(_) => {
+                let symbol = self.receiver_symbol(i)?;
+                match symbol.kind {
+                    //static members are used through the blueprint's name
+                    SymbolKind::Blueprint => Some(symbol.name.clone()),
+                    _ => symbol.value_blueprint.clone()
+                }
+            }
+            _ => None
+        }
+    }
+}
+fn lex(filename : &str, source : &str) -> Vec<Token> {
+    let mut lexer = Lexer::from_source(filename, source, Rc::new(RefCell::new(ErrorStack::new())));
+    lexer.lex();
+    lexer.tokens
+}
+//the keywords before the one at tokens[i] that defines something, like "static method" or "export funct"
+fn modifiers(tokens : &[Token], i : usize) -> String {
+    let mut words = vec![];
+    for token in tokens[..=i].iter().rev() {
+        match &token.kind {
+            TokenType::ID(word) if words.is_empty() || matches!(word.as_str(), "static" | "private" | "export") => words.push(word.clone()),
+            _ => break
+        }
+    }
+    words.reverse();
+    words.join(" ")
+}
+fn builtin(name : &str, kind : SymbolKind, signature : &str) -> Symbol {
+    Symbol {
+        name : name.to_string(),
+        kind,
+        einfo : ErrorInfo::new_empty(),
+        params : Vec::new(),
+        blueprint : None,
+        private : false,
+        value_blueprint : None,
+        from : None,
+        definition : signature.to_string(),
+        scope : 0,
+    }
+}
+///the signature of a builtin function, as it is written in docs.md
+pub fn builtin_signature(name : &str) -> Option<&'static str> {
+    Some(match name {
+        "write" => "write(args<AnyType>...) -> <NoOperation>",
+        "read" => "read() -> <AnyType>",
+        "ast_debug" => "ast_debug(args<AnyType>...) -> <String>",
+        "type" => "type(arg1<AnyType>) -> <String>",
+        "to_int" => "to_int(arg1<String>) -> <Integer>",
+        "to_float" => "to_float(arg1<String>) -> <Float>",
+        "random_int" => "random_int(arg1<Integer>, arg2<Integer>) -> <Integer>",
+        "replace" => "replace(arg1<String>, arg2<Integer>, arg3<String>) -> <String>",
+        "copy" => "copy(arg1<AnyType>) -> <AnyType>",
+        "deep_copy" => "deep_copy(arg1<AnyType>) -> <AnyType>",
+        "args" => "args() -> <List_Obj>",
+        "exit" => "exit(arg1<Integer>) -> <NoOperation>",
+        _ => return None
+    })
+}
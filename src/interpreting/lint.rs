@@ -0,0 +1,348 @@
+use crate::errors::codes::*;
+use crate::errors::error::*;
+use crate::errors::suggest::*;
+use crate::errors::warnings::*;
+use crate::parsing::ast::*;
+use crate::visitor::BUILTIN_FUNCTIONS;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+//'gscript lint', which walks a parsed script without running it and reports likely mistakes
+//names follow the same scoping rules as the visitor: functions and loops get their own scope, if blocks don't
+
+struct LintVar {
+    name : String,
+    einfo : ErrorInfo,
+    used : bool,
+    param : bool,
+}
+#[derive(PartialEq)]
+enum ScopeKind {
+    Global,
+    Function,
+    Loop,
+    //the properties and methods that a blueprint's methods can use by their bare names
+    Blueprint,
+}
+struct LintScope {
+    kind : ScopeKind,
+    vars : Vec<LintVar>,
+    //the number of parameters of each function, None when it isn't known, like for imported functions
+    funcs : HashMap<String, Option<usize>>,
+    //function and blueprint definitions, whose bodies are checked once the rest of the scope has been seen,
+    //as they usually run after everything around them has been defined
+    deferred : Vec<ASTNode>,
+    //where a function was defined, since a name in it only shadows the variables around it that were defined before it
+    defined_at : Option<(usize, usize)>,
+}
+pub struct Linter {
+    scopes : Vec<LintScope>,
+    //blueprints defined in the script, with the number of parameters of their 'create' method
+    blueprints : HashMap<String, Option<usize>>,
+    warnings : Vec<(Warning, ErrorInfo, String)>,
+    errors : Vec<(&'static ErrorCode, ErrorInfo, String)>,
+}
+impl Linter {
+    ///checks a parsed script, adding what it finds to the errorstack in the order it appears in the file
+    ///mistakes that are sure to fail when run, like calling a function that doesn't exist, are errors and the rest are warnings
+    pub fn lint(ast : &ASTNode, errorstack : &Rc<RefCell<ErrorStack>>) {
+        let mut linter = Linter {
+            scopes : Vec::new(),
+            blueprints : HashMap::new(),
+            warnings : Vec::new(),
+            errors : Vec::new(),
+        };
+        linter.push(ScopeKind::Global);
+        linter.visit(ast);
+        linter.pop();
+        linter.warnings.sort_by_key(|(_, einfo, _)| (einfo.line, einfo.col));
+        linter.errors.sort_by_key(|(_, einfo, _)| (einfo.line, einfo.col));
+        let mut errorstack = errorstack.borrow_mut();
+        for (kind, einfo, message) in linter.warnings {
+            errorstack.warn(kind, einfo, message.as_str());
+        }
+        for (code, einfo, message) in linter.errors {
+            errorstack.errors.push(GError::from_code(code, message.as_str(), einfo));
+        }
+    }
+    fn push(&mut self, kind : ScopeKind) {
+        self.scopes.push(LintScope { kind, vars : Vec::new(), funcs : HashMap::new(), deferred : Vec::new(), defined_at : None });
+    }
+    //checks the deferred definitions of the innermost scope, then reports its unused variables
+    fn pop(&mut self) {
+        while let Some(node) = self.scopes.last_mut().and_then(|s| s.deferred.pop()) {
+            match &node.kind {
+                AST::FUNC_DEF { .. } => self.visit_function(&node, false),
+                AST::CLASS { .. } => self.visit_blueprint(&node),
+                _ => {}
+            }
+        }
+        let Some(scope) = self.scopes.pop() else {
+            return;
+        };
+        //properties can be used from outside the blueprint
+        if scope.kind == ScopeKind::Blueprint {
+            return;
+        }
+        //names starting with '_' are left alone, so unused ones can be kept on purpose
+        for var in scope.vars.into_iter().filter(|v| !v.used && !v.name.starts_with('_')) {
+            if var.param {
+                self.warn(Warning::UnusedParameter, var.einfo, format!("Parameter '{}' is never used", var.name));
+            } else {
+                self.warn(Warning::UnusedVariable, var.einfo, format!("Variable '{}' is assigned but never used", var.name));
+            }
+        }
+    }
+    fn scope(&mut self) -> &mut LintScope {
+        self.scopes.last_mut().unwrap()
+    }
+    fn warn(&mut self, kind : Warning, einfo : ErrorInfo, message : String) {
+        self.warnings.push((kind, einfo, message));
+    }
+    fn define(&mut self, name : &str, einfo : ErrorInfo, param : bool, used : bool) {
+        //blueprint properties are meant to be shadowed by the parameters of their methods,
+        //and properties themselves live in each object rather than the scope around the blueprint
+        //function bodies are checked last, so the variables outside the innermost function that come after it are skipped
+        let function = self.scopes.iter().enumerate().rev().find_map(|(i, s)| s.defined_at.map(|at| (i, at)));
+        let defined_before = |i : usize, v : &LintVar| match function {
+            Some((f, at)) if i < f => (v.einfo.line, v.einfo.col) < at,
+            _ => true
+        };
+        let outer = self.scopes[..self.scopes.len()-1].iter().enumerate().rev()
+            .filter(|(_, s)| s.kind != ScopeKind::Blueprint)
+            .find_map(|(i, s)| s.vars.iter().rev().find(|v| v.name == name && defined_before(i, v)))
+            .filter(|_| self.scopes.last().is_some_and(|s| s.kind != ScopeKind::Blueprint));
+        if let Some(outer) = outer {
+            let message = format!("'{}' shadows the variable defined on line {}", name, outer.einfo.line);
+            self.warn(Warning::ShadowedVariable, einfo.clone(), message);
+        }
+        self.scope().vars.push(LintVar { name : name.to_string(), einfo, used, param });
+    }
+    fn resolve_var(&mut self, name : &str) -> Option<&mut LintVar> {
+        self.scopes.iter_mut().rev().find_map(|s| s.vars.iter_mut().rev().find(|v| v.name == name))
+    }
+    //functions and blueprints can be called before the line that defines them
+    fn hoist(&mut self, statements : &[ASTNode]) {
+        for statement in statements {
+            let statement = match &statement.kind {
+                AST::EXPORT { body } => body,
+                _ => statement
+            };
+            match &statement.kind {
+                AST::FUNC_DEF { name, args, .. } => {
+                    self.scope().funcs.insert(name.clone(), Some(args.len()));
+                }
+                AST::CLASS { name, methods, .. } => {
+                    let create = methods.get("create").map(|c| match &c.kind {
+                        AST::FUNC_DEF { args, .. } => args.len(),
+                        _ => 0
+                    });
+                    self.blueprints.insert(name.clone(), create);
+                }
+                _ => {}
+            }
+        }
+    }
+    fn visit(&mut self, node : &ASTNode) {
+        match &node.kind {
+            AST::COMPOUND { compound_value } => self.visit_compound(compound_value),
+            AST::VAR_DEF { name, value } => {
+                self.visit(value);
+                self.define(name, node.einfo.clone(), false, false);
+            }
+            AST::VAR { name } => {
+                if let Some(var) = self.resolve_var(name) {
+                    var.used = true;
+                }
+            }
+            //assigning to a variable doesn't count as using it
+            AST::VAR_REASSIGN { value, .. } => self.visit(value),
+            AST::FUNC_DEF { .. } | AST::CLASS { .. } => self.scope().deferred.push(node.clone()),
+            AST::FUNC_CALL { name, args } => {
+                for arg in args {
+                    self.visit(arg);
+                }
+                self.check_call(node, name, args.len());
+            }
+            AST::NEW { name, module_path, args } => {
+                for arg in args {
+                    self.visit(arg);
+                }
+                if let (true, Some(Some(count))) = (module_path.is_empty(), self.blueprints.get(name)) {
+                    if *count != args.len() {
+                        let message = format!("Blueprint '{}' requires {} argument(s) for 'create', not {}", name, count, args.len());
+                        self.errors.push((&WRONG_ARGUMENT_COUNT, node.einfo.clone(), message));
+                    }
+                }
+            }
+            AST::RETURN { value } => self.visit(value),
+            AST::BINOP { left, right, .. } => {
+                self.visit(left);
+                self.visit(right);
+            }
+            AST::UNOP { body, .. } => self.visit(body),
+            AST::LIST { contents } => {
+                for item in contents {
+                    self.visit(&item.borrow());
+                }
+            }
+            AST::INDEX { target, indices } => {
+                self.visit(target);
+                for index in indices {
+                    self.visit(index);
+                }
+            }
+            AST::LIST_REASSIGN { target, value } => {
+                self.visit(target);
+                self.visit(value);
+            }
+            AST::OBJECT_INDEX { object, property } => {
+                self.visit(object);
+                self.visit_property(property);
+            }
+            AST::OBJECT_REASSIGN { object_index, value } => {
+                self.visit(object_index);
+                self.visit(value);
+            }
+            AST::IF { conditions, bodies, else_body } => {
+                for (condition, body) in conditions.iter().zip(bodies) {
+                    self.visit(condition);
+                    self.visit(body);
+                }
+                if let Some(body) = else_body {
+                    self.visit(body);
+                }
+            }
+            AST::WHILE { condition, body } => {
+                self.push(ScopeKind::Loop);
+                self.visit(condition);
+                self.visit(body);
+                self.pop();
+            }
+            //imported names are used by whoever imports them, and could be a variable, function or blueprint
+            AST::IMPORT { object_name, names, .. } => match names {
+                None => self.define(object_name, node.einfo.clone(), false, true),
+                Some(names) => {
+                    for (_, alias) in names {
+                        self.define(alias, node.einfo.clone(), false, true);
+                        self.scope().funcs.insert(alias.clone(), None);
+                        self.blueprints.insert(alias.clone(), None);
+                    }
+                }
+            },
+            AST::EXPORT { body } => {
+                self.visit(body);
+                if let AST::VAR_DEF { name, .. } = &body.kind {
+                    if let Some(var) = self.resolve_var(name) {
+                        var.used = true;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    fn visit_compound(&mut self, statements : &[ASTNode]) {
+        self.hoist(statements);
+        let mut ended = false;
+        for statement in statements {
+            if let AST::NOOP | AST::EOF = statement.kind {
+                continue;
+            }
+            //only the first unreachable statement is reported, but the rest are still checked
+            if ended {
+                self.warn(Warning::UnreachableCode, statement.einfo.clone(), "This code is never run, as it comes after a 'return' or 'break'".to_string());
+                ended = false;
+            }
+            self.visit(statement);
+            if let AST::RETURN { .. } | AST::BREAK = statement.kind {
+                ended = true;
+            }
+        }
+    }
+    //the part after a '.' names a property or method rather than a variable or function
+    fn visit_property(&mut self, property : &ASTNode) {
+        match &property.kind {
+            AST::VAR { .. } => {}
+            AST::FUNC_CALL { args, .. } => {
+                for arg in args {
+                    self.visit(arg);
+                }
+            }
+            AST::INDEX { target, indices } => {
+                self.visit_property(target);
+                for index in indices {
+                    self.visit(index);
+                }
+            }
+            _ => self.visit(property)
+        }
+    }
+    fn check_call(&mut self, node : &ASTNode, name : &str, arg_count : usize) {
+        if BUILTIN_FUNCTIONS.contains(&name) || name == "_PRIMITIVE" {
+            return;
+        }
+        match self.scopes.iter().rev().find_map(|s| s.funcs.get(name)) {
+            Some(Some(count)) if *count != arg_count => {
+                let message = format!("Function '{}' requires {} argument(s), not {}", name, count, arg_count);
+                self.errors.push((&WRONG_ARGUMENT_COUNT, node.einfo.clone(), message));
+            }
+            Some(_) => {}
+            None => {
+                let funcs : Vec<&str> = self.scopes.iter().flat_map(|s| s.funcs.keys()).map(String::as_str).collect();
+                let message = with_suggestion(
+                    format!("Function '{}' does not exist in the current scope", name),
+                    name,
+                    funcs.into_iter().chain(BUILTIN_FUNCTIONS),
+                );
+                self.errors.push((&UNDEFINED_FUNCTION, node.einfo.clone(), message));
+            }
+        }
+    }
+    fn visit_function(&mut self, fdef : &ASTNode, method : bool) {
+        let AST::FUNC_DEF { body, args, .. } = &fdef.kind else {
+            return;
+        };
+        self.push(ScopeKind::Function);
+        self.scope().defined_at = Some((fdef.einfo.line, fdef.einfo.col));
+        if method {
+            self.define("self", fdef.einfo.clone(), false, true);
+        }
+        for arg in args {
+            if let AST::VAR_DEF { name, .. } = &arg.kind {
+                self.define(name, arg.einfo.clone(), true, false);
+            }
+        }
+        self.visit(body);
+        self.pop();
+    }
+    fn visit_blueprint(&mut self, bdef : &ASTNode) {
+        let AST::CLASS { name, properties, methods, getters, setters, static_properties, static_methods, .. } = &bdef.kind else {
+            return;
+        };
+        if !methods.contains_key("create") {
+            let message = format!("Blueprint '{}' has no 'create' method, so 'new {}' will fail", name, name);
+            self.warn(Warning::MissingConstructor, bdef.einfo.clone(), message);
+        }
+        //methods and accessors are kept in hash maps, so they are sorted to check them in the order they were written
+        let in_order = |members : &HashMap<String, ASTNode>| {
+            let mut members : Vec<ASTNode> = members.values().cloned().collect();
+            members.sort_by_key(|m| (m.einfo.line, m.einfo.col));
+            members
+        };
+        self.push(ScopeKind::Blueprint);
+        for (_, prop) in properties.iter().chain(static_properties) {
+            self.visit(prop);
+        }
+        //'create' isn't added to the object, so it can't be called by name
+        for method in methods.iter().chain(static_methods).filter(|(n, _)| *n != "create") {
+            if let AST::FUNC_DEF { args, .. } = &method.1.kind {
+                self.scope().funcs.insert(method.0.clone(), Some(args.len()));
+            }
+        }
+        for member in [methods, static_methods, getters, setters].into_iter().flat_map(in_order) {
+            self.visit_function(&member, true);
+        }
+        self.pop();
+    }
+}
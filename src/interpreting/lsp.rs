@@ -0,0 +1,449 @@
+use crate::analysis::*;
+use crate::errors::error::*;
+use crate::lint::Linter;
+use crate::parsing::parser::Parser;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::path::Path;
+use std::rc::Rc;
+
+//'gscript lsp', a language server that editors start and talk to over standard input and output
+//each message is a JSON-RPC object after a 'Content-Length' header, as described in the Language Server Protocol
+//positions in the protocol start at 0, while ErrorInfo's start at 1, and characters are counted as chars rather than UTF-16 units
+
+///a JSON value, just enough of it for the messages of the language server
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    //kept in order, so replies are written the way they were built
+    Object(Vec<(String, Json)>),
+}
+static NULL : Json = Json::Null;
+impl Json {
+    pub fn parse(s : &str) -> Option<Json> {
+        let mut chars = s.chars().peekable();
+        let value = parse_value(&mut chars)?;
+        skip_space(&mut chars);
+        chars.peek().is_none().then_some(value)
+    }
+    pub fn object<'a>(pairs : impl IntoIterator<Item = (&'a str, Json)>) -> Json {
+        Json::Object(pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+    }
+    ///the value of a key in an object, or null if there isn't one
+    pub fn get(&self, key : &str) -> &Json {
+        match self {
+            Json::Object(pairs) => pairs.iter().find(|(k, _)| k == key).map_or(&NULL, |(_, v)| v),
+            _ => &NULL
+        }
+    }
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None
+        }
+    }
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            Json::Number(n) if *n >= 0.0 => Some(*n as usize),
+            _ => None
+        }
+    }
+    pub fn as_array(&self) -> &[Json] {
+        match self {
+            Json::Array(items) => items,
+            _ => &[]
+        }
+    }
+}
+impl fmt::Display for Json {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Number(n) if n.fract() == 0.0 && n.abs() < 1e15 => write!(f, "{}", *n as i64),
+            Json::Number(n) => write!(f, "{}", n),
+            Json::String(s) => write!(f, "{}", json_string(s)),
+            Json::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    write!(f, "{}{}", if i > 0 { "," } else { "" }, item)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(pairs) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    write!(f, "{}{}:{}", if i > 0 { "," } else { "" }, json_string(key), value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+type Chars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+fn skip_space(chars : &mut Chars) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+fn parse_value(chars : &mut Chars) -> Option<Json> {
+    skip_space(chars);
+    match chars.peek()? {
+        '"' => parse_string(chars).map(Json::String),
+        '[' => {
+            chars.next();
+            let mut items = Vec::new();
+            skip_space(chars);
+            if chars.next_if_eq(&']').is_some() {
+                return Some(Json::Array(items));
+            }
+            loop {
+                items.push(parse_value(chars)?);
+                skip_space(chars);
+                match chars.next()? {
+                    ',' => {}
+                    ']' => return Some(Json::Array(items)),
+                    _ => return None
+                }
+            }
+        }
+        '{' => {
+            chars.next();
+            let mut pairs = Vec::new();
+            skip_space(chars);
+            if chars.next_if_eq(&'}').is_some() {
+                return Some(Json::Object(pairs));
+            }
+            loop {
+                skip_space(chars);
+                let key = parse_string(chars)?;
+                skip_space(chars);
+                chars.next_if_eq(&':')?;
+                pairs.push((key, parse_value(chars)?));
+                skip_space(chars);
+                match chars.next()? {
+                    ',' => {}
+                    '}' => return Some(Json::Object(pairs)),
+                    _ => return None
+                }
+            }
+        }
+        c if c.is_ascii_alphabetic() => {
+            let mut word = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+                word.push(c);
+            }
+            match word.as_str() {
+                "null" => Some(Json::Null),
+                "true" => Some(Json::Bool(true)),
+                "false" => Some(Json::Bool(false)),
+                _ => None
+            }
+        }
+        _ => {
+            let mut number = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_digit() || "+-.eE".contains(*c)) {
+                number.push(c);
+            }
+            number.parse().ok().map(Json::Number)
+        }
+    }
+}
+fn parse_string(chars : &mut Chars) -> Option<String> {
+    chars.next_if_eq(&'"')?;
+    let mut s = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(s),
+            '\\' => match chars.next()? {
+                'n' => s.push('\n'),
+                'r' => s.push('\r'),
+                't' => s.push('\t'),
+                'b' => s.push('\u{8}'),
+                'f' => s.push('\u{c}'),
+                'u' => {
+                    let mut code = parse_hex(chars)?;
+                    //characters outside the basic plane are written as a pair of surrogates
+                    if (0xD800..0xDC00).contains(&code) && chars.next_if_eq(&'\\').is_some() && chars.next_if_eq(&'u').is_some() {
+                        let low = parse_hex(chars)?;
+                        code = 0x10000 + ((code - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF);
+                    }
+                    s.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+                c => s.push(c)
+            },
+            c => s.push(c)
+        }
+    }
+}
+fn parse_hex(chars : &mut Chars) -> Option<u32> {
+    let digits : String = (0..4).map_while(|_| chars.next()).collect();
+    u32::from_str_radix(&digits, 16).ok()
+}
+
+pub struct LanguageServer {
+    //the text of each open document by URI, which is sent in full on every change
+    documents : HashMap<String, String>,
+    shutdown : bool,
+}
+impl LanguageServer {
+    pub fn new() -> LanguageServer {
+        LanguageServer { documents : HashMap::new(), shutdown : false }
+    }
+    ///answers messages from standard input until the editor sends 'exit', and returns the exit code
+    pub fn run(&mut self) -> i32 {
+        let mut input = std::io::stdin().lock();
+        let mut output = std::io::stdout().lock();
+        while let Some(body) = read_message(&mut input) {
+            let replies = match Json::parse(&body) {
+                Some(message) if message.get("method").as_str() == Some("exit") => return if self.shutdown { 0 } else { 1 },
+                Some(message) => self.handle(&message),
+                None => vec![error_response(&Json::Null, -32700, "Could not parse the message as JSON")]
+            };
+            for reply in replies {
+                let reply = reply.to_string();
+                let _ = write!(output, "Content-Length: {}\r\n\r\n{}", reply.len(), reply);
+                let _ = output.flush();
+            }
+        }
+        1
+    }
+    ///the messages to send back for one message from the editor
+    pub fn handle(&mut self, message : &Json) -> Vec<Json> {
+        let id = message.get("id");
+        let params = message.get("params");
+        let uri = params.get("textDocument").get("uri").as_str().unwrap_or_default().to_string();
+        let result = match message.get("method").as_str().unwrap_or_default() {
+            "initialize" => Json::object([
+                ("capabilities", Json::object([
+                    //the whole document is sent on every change
+                    ("textDocumentSync", Json::Number(1.0)),
+                    ("definitionProvider", Json::Bool(true)),
+                    ("hoverProvider", Json::Bool(true)),
+                    ("completionProvider", Json::object([("triggerCharacters", Json::Array(vec![Json::String(".".to_string())]))])),
+                ])),
+                ("serverInfo", Json::object([
+                    ("name", Json::String("gscript".to_string())),
+                    ("version", Json::String(env!("CARGO_PKG_VERSION").to_string())),
+                ])),
+            ]),
+            "shutdown" => {
+                self.shutdown = true;
+                Json::Null
+            }
+            "textDocument/didOpen" => {
+                let text = params.get("textDocument").get("text").as_str().unwrap_or_default();
+                self.documents.insert(uri.clone(), text.to_string());
+                return vec![self.diagnostics(&uri)];
+            }
+            "textDocument/didChange" => {
+                if let Some(text) = params.get("contentChanges").as_array().last().and_then(|c| c.get("text").as_str()) {
+                    self.documents.insert(uri.clone(), text.to_string());
+                }
+                return vec![self.diagnostics(&uri)];
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                return vec![publish_diagnostics(&uri, Vec::new())];
+            }
+            "textDocument/definition" => self.definition(&uri, params.get("position")),
+            "textDocument/hover" => self.hover(&uri, params.get("position")),
+            "textDocument/completion" => self.completion(&uri, params.get("position")),
+            //notifications like 'initialized' need no reply
+            _ if id == &Json::Null => return Vec::new(),
+            method => return vec![error_response(id, -32601, &format!("Unknown method '{}'", method))]
+        };
+        if id == &Json::Null {
+            return Vec::new();
+        }
+        vec![Json::object([("jsonrpc", Json::String("2.0".to_string())), ("id", id.clone()), ("result", result)])]
+    }
+    //the errors and warnings that 'gscript check' and 'gscript lint' would report for a document
+    fn diagnostics(&self, uri : &str) -> Json {
+        let path = uri_to_path(uri);
+        let text = self.documents.get(uri).map_or("", String::as_str);
+        let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+        //gscript.cfg applies the same way it does on the command line
+        if let Some(dir) = Path::new(&path).parent() {
+            let _ = errorstack.borrow_mut().config.load(dir);
+        }
+        let ast_compound = Parser::parse_source(&path, text, Rc::clone(&errorstack));
+        if errorstack.borrow().errors.is_empty() {
+            Linter::lint(&ast_compound, &errorstack);
+        }
+        let errorstack = errorstack.borrow();
+        let mut diagnostics = Vec::new();
+        for error in &errorstack.errors {
+            let code = error.code().map_or(error.kind(), str::to_string);
+            diagnostics.push(diagnostic(error.line(), error.col(), error.col_end(), 1, &code, error.message()));
+        }
+        for warning in &errorstack.warnings {
+            let einfo = &warning.einfo;
+            diagnostics.push(diagnostic(einfo.line, einfo.col, einfo.col_end, 2, warning.kind.name(), &warning.message));
+        }
+        publish_diagnostics(uri, diagnostics)
+    }
+    fn analyze(&self, uri : &str) -> Option<Analysis> {
+        let text = self.documents.get(uri)?;
+        Some(Analysis::analyze(&uri_to_path(uri), text))
+    }
+    fn definition(&self, uri : &str, position : &Json) -> Json {
+        let (Some(analysis), Some((line, col))) = (self.analyze(uri), to_line_col(position)) else {
+            return Json::Null;
+        };
+        let Some(symbol) = analysis.symbol_at(line, col) else {
+            return Json::Null;
+        };
+        //standard library definitions are only found if its files are in the module search path
+        let target = if symbol.einfo.file == uri_to_path(uri) {
+            Some(uri.to_string())
+        } else {
+            let filename = Path::new(&symbol.einfo.file).file_name().map(|f| f.to_string_lossy().to_string()).unwrap_or_default();
+            crate::modules::resolve_std(&filename)
+                .and_then(|p| std::path::absolute(p).ok())
+                .map(|p| path_to_uri(&p.to_string_lossy()))
+        };
+        match target {
+            Some(target) => Json::object([("uri", Json::String(target)), ("range", range(&symbol.einfo))]),
+            None => Json::Null
+        }
+    }
+    fn hover(&self, uri : &str, position : &Json) -> Json {
+        let (Some(analysis), Some((line, col))) = (self.analyze(uri), to_line_col(position)) else {
+            return Json::Null;
+        };
+        match analysis.hover(line, col) {
+            Some((signature, einfo)) => Json::object([
+                ("contents", Json::object([
+                    ("kind", Json::String("markdown".to_string())),
+                    ("value", Json::String(format!("```gscript\n{}\n```", signature))),
+                ])),
+                ("range", range(&einfo)),
+            ]),
+            None => Json::Null
+        }
+    }
+    fn completion(&self, uri : &str, position : &Json) -> Json {
+        let (Some(analysis), Some((line, col))) = (self.analyze(uri), to_line_col(position)) else {
+            return Json::Array(Vec::new());
+        };
+        Json::Array(analysis.completions(line, col).iter().map(|symbol| Json::object([
+            ("label", Json::String(symbol.name.clone())),
+            ("kind", Json::Number(completion_kind(symbol.kind))),
+            ("detail", Json::String(symbol.signature())),
+        ])).collect())
+    }
+}
+impl Default for LanguageServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+//reads the body of the next message, or None once the input is closed
+fn read_message(input : &mut impl BufRead) -> Option<String> {
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header).ok()? == 0 {
+            return None;
+        }
+        let header = header.trim_end();
+        if header.is_empty() && length.is_some() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+    let mut body = vec![0; length?];
+    input.read_exact(&mut body).ok()?;
+    String::from_utf8(body).ok()
+}
+fn error_response(id : &Json, code : i32, message : &str) -> Json {
+    Json::object([
+        ("jsonrpc", Json::String("2.0".to_string())),
+        ("id", id.clone()),
+        ("error", Json::object([("code", Json::Number(code as f64)), ("message", Json::String(message.to_string()))])),
+    ])
+}
+fn publish_diagnostics(uri : &str, diagnostics : Vec<Json>) -> Json {
+    Json::object([
+        ("jsonrpc", Json::String("2.0".to_string())),
+        ("method", Json::String("textDocument/publishDiagnostics".to_string())),
+        ("params", Json::object([("uri", Json::String(uri.to_string())), ("diagnostics", Json::Array(diagnostics))])),
+    ])
+}
+fn diagnostic(line : usize, col : usize, col_end : usize, severity : u8, code : &str, message : &str) -> Json {
+    Json::object([
+        ("range", range(&ErrorInfo::new(String::new(), String::new(), line, col, col_end))),
+        ("severity", Json::Number(severity as f64)),
+        ("code", Json::String(code.to_string())),
+        ("source", Json::String("gscript".to_string())),
+        ("message", Json::String(message.to_string())),
+    ])
+}
+fn range(einfo : &ErrorInfo) -> Json {
+    let position = |col : usize| Json::object([
+        ("line", Json::Number(einfo.line.saturating_sub(1) as f64)),
+        ("character", Json::Number(col.saturating_sub(1) as f64)),
+    ]);
+    Json::object([("start", position(einfo.col)), ("end", position(einfo.col_end.max(einfo.col)))])
+}
+//a protocol position as the line and column of ErrorInfo
+fn to_line_col(position : &Json) -> Option<(usize, usize)> {
+    Some((position.get("line").as_usize()? + 1, position.get("character").as_usize()? + 1))
+}
+fn completion_kind(kind : SymbolKind) -> f64 {
+    match kind {
+        SymbolKind::Method => 2.0,
+        SymbolKind::Function | SymbolKind::Builtin => 3.0,
+        SymbolKind::Variable | SymbolKind::Parameter | SymbolKind::Imported => 6.0,
+        SymbolKind::Blueprint => 7.0,
+        SymbolKind::Module => 9.0,
+        SymbolKind::Property => 10.0,
+        SymbolKind::Keyword => 14.0,
+    }
+}
+///the path of a 'file://' URI, which is also used as the filename in diagnostics
+pub fn uri_to_path(uri : &str) -> String {
+    let path = uri.strip_prefix("file://").unwrap_or(uri);
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i+1..i+3).and_then(|h| std::str::from_utf8(h).ok()).and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    let path = String::from_utf8_lossy(&decoded).to_string();
+    //'file:///C:/dir' on Windows
+    match path.as_bytes() {
+        [b'/', _, b':', ..] => path[1..].to_string(),
+        _ => path
+    }
+}
+pub fn path_to_uri(path : &str) -> String {
+    let path = path.replace('\\', "/");
+    let mut uri = String::from(if path.starts_with('/') { "file://" } else { "file:///" });
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' | b':' => uri.push(byte as char),
+            _ => uri.push_str(&format!("%{:02X}", byte))
+        }
+    }
+    uri
+}
@@ -0,0 +1,55 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+///the standard library modules, embedded in the binary so that it can run from anywhere
+pub const STD_MODULES : &[(&str, &str)] = &[
+    ("string.gsc", include_str!("../../std/string.gsc")),
+    ("integer.gsc", include_str!("../../std/integer.gsc")),
+];
+
+///directories that are searched for the standard library and for imports that aren't next to the importing file
+///these are the entries of the GSCRIPT_PATH environment variable, then the 'std' directory next to the binary
+///(or at the root of the repository for binaries built with cargo), then 'std' in the current directory
+pub fn search_path() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(gscript_path) = env::var_os("GSCRIPT_PATH") {
+        dirs.extend(env::split_paths(&gscript_path).filter(|p| !p.as_os_str().is_empty()));
+    }
+    if let Some(exe_dir) = env::current_exe().ok().as_deref().and_then(Path::parent) {
+        dirs.push(exe_dir.join("std"));
+        //target/debug/gscriptrust -> std
+        if let Some(repo_root) = exe_dir.parent().and_then(Path::parent) {
+            dirs.push(repo_root.join("std"));
+        }
+    }
+    dirs.push(PathBuf::from("std"));
+    dirs
+}
+///finds a standard library file like 'string.gsc' in the search path
+pub fn resolve_std(filename : &str) -> Option<PathBuf> {
+    search_path().into_iter().map(|dir| dir.join(filename)).find(|p| p.is_file())
+}
+///the copy of a standard library module like 'string.gsc' that is embedded in the binary
+pub fn embedded_std(filename : &str) -> Option<&'static str> {
+    STD_MODULES.iter().find(|(name, _)| *name == filename).map(|(_, source)| *source)
+}
+///where the source of an imported module comes from
+#[derive(Debug, PartialEq)]
+pub enum ModuleSource {
+    File(PathBuf),
+    //a standard library module embedded in the binary, with the name it is reported under
+    Embedded(String, &'static str),
+}
+///finds the module imported by 'import "filename"' in the file 'importer'
+///paths are relative to the importing file. If there is no such file, a standard library module
+///is taken from the binary (or the search path if std_from_disk is set), and anything else from the search path
+pub fn resolve_import(filename : &str, importer : &str, std_from_disk : bool) -> Option<ModuleSource> {
+    let relative = Path::new(importer).parent().unwrap_or(Path::new("")).join(filename);
+    if relative.is_file() {
+        return Some(ModuleSource::File(relative));
+    }
+    match embedded_std(filename) {
+        Some(source) if !std_from_disk => Some(ModuleSource::Embedded(format!("std/{}", filename), source)),
+        _ => resolve_std(filename).map(ModuleSource::File),
+    }
+}
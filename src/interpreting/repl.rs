@@ -0,0 +1,154 @@
+use crate::errors::error::*;
+use crate::errors::warnings::*;
+use crate::parsing::ast::*;
+use crate::parsing::parser::*;
+use crate::visitor::*;
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+///an interactive session, which keeps its variables, functions and blueprints between inputs
+pub struct Repl {
+    visitor : Visitor,
+    errorstack : Rc<RefCell<ErrorStack>>,
+    inputs : usize,
+    ///set once exit() is called, the session ends with this code
+    pub exit_code : Option<i32>,
+}
+impl Repl {
+    pub fn new(std_from_disk : bool) -> Repl {
+        let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+        let mut visitor = Visitor::new(Rc::clone(&errorstack));
+        visitor.std_from_disk = std_from_disk;
+        if let Err(e) = visitor.preload_std() {
+            errorstack.borrow_mut().errors.push(e);
+        }
+        errorstack.borrow().print_dump();
+        errorstack.borrow_mut().clear();
+        Repl { visitor, errorstack, inputs : 0, exit_code : None }
+    }
+    ///sets which warnings are reported for the inputs that follow
+    pub fn set_warnings(&mut self, config : WarningConfig) {
+        self.errorstack.borrow_mut().config = config;
+    }
+    ///reads inputs from a line editor, which keeps a history of them, until 'exit' or the end of input
+    ///lines are collected until every bracket is closed, so blocks can span multiple lines
+    ///returns the code passed to exit(), or 0
+    pub fn run(&mut self) -> i32 {
+        println!("GScript REPL (enter 'exit' to quit)");
+        let mut editor = match DefaultEditor::new() {
+            Ok(editor) => editor,
+            Err(e) => {
+                eprintln!("Could not start the line editor: {}", e);
+                return 1;
+            }
+        };
+        let mut input = String::new();
+        loop {
+            let line = match editor.readline(if input.is_empty() { "> " } else { "... " }) {
+                Ok(line) => line,
+                //ctrl-c drops the input being typed rather than ending the session
+                Err(ReadlineError::Interrupted) => {
+                    input.clear();
+                    continue;
+                }
+                Err(_) => {
+                    println!();
+                    break;
+                }
+            };
+            if input.is_empty() && line.trim() == "exit" {
+                break;
+            }
+            input.push_str(&line);
+            input.push('\n');
+            if Repl::is_incomplete(&input) {
+                continue;
+            }
+            if !input.trim().is_empty() {
+                //an input that spans several lines is recalled as a whole
+                let _ = editor.add_history_entry(input.trim_end());
+                if let Some(output) = self.eval(&input) {
+                    println!("{}", output);
+                }
+            }
+            input.clear();
+            if self.exit_code.is_some() {
+                break;
+            }
+        }
+        self.exit_code.unwrap_or(0)
+    }
+    ///runs one complete input, returning the value of its last statement if it has one
+    pub fn eval(&mut self, source : &str) -> Option<String> {
+        self.inputs += 1;
+        let name = format!("<repl {}>", self.inputs);
+        //the semicolon after the last statement is optional
+        let mut source = source.trim_end().to_string();
+        if !source.ends_with(';') {
+            source.push(';');
+        }
+        let ast_compound = Parser::parse_source(&name, &source, Rc::clone(&self.errorstack));
+        let mut res = Ok(None);
+        if self.errorstack.borrow().errors.is_empty() {
+            if let AST::COMPOUND { compound_value } = &ast_compound.kind {
+                //the parser ends the compound with a NOOP after the final semicolon
+                for statement in compound_value {
+                    if let AST::NOOP | AST::EOF = statement.kind {
+                        continue;
+                    }
+                    res = self.visitor.visit(statement).map(Some);
+                    if res.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+        let output = match res {
+            Ok(Some(value)) => {
+                let value = match value.kind {
+                    AST::RETURN { value } => *value,
+                    _ => value,
+                };
+                match &value.kind {
+                    AST::STRING { .. } | AST::INT { .. } | AST::FLOAT { .. } | AST::BOOL { .. } |
+                    AST::LIST { .. } | AST::OBJECT { .. } | AST::TYPE { .. } => Some(self.visitor.node_to_string(&value)),
+                    _ => None
+                }
+            }
+            Ok(None) => None,
+            Err(e) => {
+                self.visitor.recover();
+                match e.exit_code() {
+                    Some(code) => self.exit_code = Some(code),
+                    None => self.errorstack.borrow_mut().errors.push(e)
+                }
+                None
+            }
+        };
+        self.errorstack.borrow().print_dump();
+        self.errorstack.borrow_mut().clear();
+        output
+    }
+    ///whether the input has more opening brackets than closing ones, ignoring strings and comments
+    pub fn is_incomplete(source : &str) -> bool {
+        let mut depth = 0;
+        let mut closing : Option<char> = None;
+        for c in source.chars() {
+            if let Some(end) = closing {
+                if c == end {
+                    closing = None;
+                }
+                continue;
+            }
+            match c {
+                '"' | '\'' | '\\' => closing = Some(c),
+                '{' | '(' | '[' => depth += 1,
+                '}' | ')' | ']' => depth -= 1,
+                _ => {}
+            }
+        }
+        depth > 0
+    }
+}
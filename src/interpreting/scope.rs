@@ -1,4 +1,4 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc, rc::Weak};
+use std::{cell::RefCell, collections::HashMap, collections::HashSet, rc::Rc, rc::Weak};
 use crate::ast::*;
 
 
@@ -7,7 +7,18 @@ pub struct Scope {
     pub parent : Option<Weak<RefCell<Scope>>>,
     pub variables : HashMap<String, Rc<RefCell<ASTNode>>>,
     pub functions : HashMap<String, ASTNode>,
-    pub classes : HashMap<String, ASTNode>
+    pub classes : HashMap<String, ASTNode>,
+    //accessors of an object, called when its property is read from or assigned to with the dot operator
+    pub getters : HashMap<String, ASTNode>,
+    pub setters : HashMap<String, ASTNode>,
+    //names of members that can only be accessed from within the methods of the object's blueprint
+    pub private : HashSet<String>,
+    //whether this is the scope of an object made with 'new', whose parent is the static scope of its blueprint
+    pub instance : bool,
+    //names marked with 'export' in a module, if there are none then everything in the module is visible to importers
+    pub exported : HashSet<String>,
+    //the module scope that an imported function was defined in, so that it still sees the globals of its home module
+    pub function_homes : HashMap<String, Rc<RefCell<Scope>>>
 }
 impl Scope {
     pub fn new(parent : Option<Rc<RefCell<Scope>>>) -> Scope {
@@ -16,6 +27,12 @@ impl Scope {
             variables : HashMap::new(),
             functions : HashMap::new(),
             classes : HashMap::new(),
+            getters : HashMap::new(),
+            setters : HashMap::new(),
+            private : HashSet::new(),
+            instance : false,
+            exported : HashSet::new(),
+            function_homes : HashMap::new(),
         }
     }
     pub fn add_blueprint(&mut self, node : &ASTNode) -> Result<(), String> {
@@ -58,6 +75,12 @@ impl Scope {
             _ => Err("Not a valid variable definition".to_string())
         }
     }
+    //defines a variable in this scope only, shadowing any variable with the same name in the parent scopes
+    pub fn define_var(&mut self, node : ASTNode) {
+        if let AST::VAR_DEF { name, .. } = &node.kind {
+            self.variables.insert(name.clone(), Rc::new(RefCell::new(node)));
+        }
+    }
     pub fn set_var(&mut self, name : String, node : &ASTNode) -> Result<(), String> {
         if let Some(existing) = self.variables.get_mut(&name) {
             *existing = Rc::new(RefCell::new(node.clone()));
@@ -95,6 +118,23 @@ impl Scope {
             }
         })
     }
+    //names of the variables, functions and blueprints visible from this scope, including its parents
+    pub fn visible_vars(&self) -> Vec<String> {
+        self.visible_names(|s| s.variables.keys().cloned().collect())
+    }
+    pub fn visible_funcs(&self) -> Vec<String> {
+        self.visible_names(|s| s.functions.keys().cloned().collect())
+    }
+    pub fn visible_blueprints(&self) -> Vec<String> {
+        self.visible_names(|s| s.classes.keys().cloned().collect())
+    }
+    fn visible_names(&self, names : fn(&Scope) -> Vec<String>) -> Vec<String> {
+        let mut all = names(self);
+        if let Some(pscope) = self.parent.as_ref().and_then(|p| p.upgrade()) {
+            all.extend(pscope.borrow().visible_names(names));
+        }
+        all
+    }
     pub fn resolve_var_cloned(&self, name : String) -> Option<ASTNode> {
         Some(self.resolve_var(name)?.borrow().clone())
     }
@@ -107,6 +147,24 @@ impl Scope {
             }
         })
     }
+    //like resolve_func, but also returns the scope that the function was found in
+    pub fn resolve_func_owner(starting_scope : Rc<RefCell<Scope>>, name : String) -> Option<(ASTNode, Rc<RefCell<Scope>>)> {
+        let mut cs = starting_scope;
+        loop {
+            let par = {
+                let borrowed_cs = cs.borrow();
+                if let Some(fdef) = borrowed_cs.functions.get(&name) {
+                    let owner = borrowed_cs.function_homes.get(&name).cloned().unwrap_or(cs.clone());
+                    return Some((fdef.clone(), owner));
+                }
+                borrowed_cs.parent.as_ref().and_then(|p| p.upgrade())
+            };
+            match par {
+                Some(p) => cs = p,
+                None => return None
+            }
+        }
+    }
     //-- REVISED BY CHATGPT ---
     // again, the reason for this is because I was dropping an owned value
     // while still having a borrow for it 
@@ -138,6 +196,9 @@ impl Scope {
                 for (name, bdef) in &s_borrowed.classes {
                     new_s.borrow_mut().classes.insert(name.clone(), bdef.clone());
                 }
+                new_s.borrow_mut().getters = s_borrowed.getters.clone();
+                new_s.borrow_mut().setters = s_borrowed.setters.clone();
+                new_s.borrow_mut().private = s_borrowed.private.clone();
                 // Do not recurse on parent to avoid cycles; just share the parent (Copilot edited ts)
                 new_s.borrow_mut().parent = s_borrowed.parent.clone();
                 Some(new_s)
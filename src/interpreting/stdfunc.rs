@@ -1,45 +1,50 @@
+use crate::codes::*;
 use crate::error::*;
 use crate::visitor::*;
 use crate::parsing::ast::*;
+use crate::scope::*;
 use rand::Rng;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
 
 
 
 ///GScript: Writes formatted AST to stdout
-pub fn std_func_debug(_v : &mut Visitor, args : &Vec<ASTNode>) -> ASTNode {
+pub fn std_func_debug(_v : &mut Visitor, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
     for arg in args {
         println!("{:#?}", arg);
     }
-    ASTNode::new_noop()
+    Ok(ASTNode::new_noop())
 }
 ///GScript: Writes to stdout
-pub fn std_func_write(v : &mut Visitor, args : &Vec<ASTNode> ) -> ASTNode {
+pub fn std_func_write(v : &mut Visitor, args : &Vec<ASTNode> ) -> Result<ASTNode, GError> {
     for arg in args {
-        let ast = v.visit(arg);
+        let ast = v.visit(arg)?;
         print!("{}", v.node_to_string(&ast));
     }
     println!();
-    ASTNode::new_noop()
+    Ok(ASTNode::new_noop())
 }
 ///GScript: Reads line from stdin and returns string of it, no args
-pub fn std_func_read(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> ASTNode {
+pub fn std_func_read(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
     if args.len() != 0 {
-        v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::FunctionError, format!("Function 'read' requires 0 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
-        return ASTNode::new_noop();
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'read' requires 0 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
     } else {
         let mut input = String::new();
         let _ = std::io::stdin().read_line(&mut input).unwrap();
         let input = input.trim_end().to_string();
-        return ASTNode::new(AST::STRING{str_value : input}, node.einfo.clone());
+        return Ok(ASTNode::new(AST::STRING{str_value : input}, node.einfo.clone()));
     }
 }
 ///GScript: returns AST_TYPE of arg[0]
-pub fn std_func_type(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> ASTNode {
+pub fn std_func_type(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
     if args.len() != 1 {
-        v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::FunctionError, format!("Function 'type' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
-        return ASTNode::new_noop();
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'type' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
     } else {
-        let arg = v.visit(&args[0]);
+        let arg = v.visit(&args[0])?;
         let typeval : String;
         match &arg.kind {
             AST::STRING{..} => typeval = "String".to_string(),
@@ -50,155 +55,221 @@ pub fn std_func_type(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) ->
             AST::OBJECT{class_name, ..} => typeval = class_name.clone(),
             _ => typeval = "Null".to_string()
         }
-        ASTNode::new(AST::TYPE{type_value : typeval}, args[0].einfo.clone())
+        Ok(ASTNode::new(AST::TYPE{type_value : typeval}, args[0].einfo.clone()))
     }
 }
 ///GScript: converts AST_STRING to AST_INT
-pub fn std_func_to_int(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> ASTNode {
+pub fn std_func_to_int(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
     if args.len() != 1 {
-        v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::FunctionError, format!("Function 'to_int' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
-        return ASTNode::new_noop();
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'to_int' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
     } else {
-        let arg = v.visit(&args[0]);
-        let mut numval= 0;
-        match &arg.kind {
+        let arg = v.visit(&args[0])?;
+        let numval = match &arg.kind {
             AST::STRING{str_value} => {
-                numval = str_value.clone().parse::<i32>().unwrap_or_else(|_| {
-                    v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::TypeError, format!("Could not cast '{}' to type 'Integer'", str_value).as_str(), node.einfo.clone()));
-                    v.errorstack.borrow().terminate_gs();
-                    0
-                });
-                
+                str_value.clone().parse::<i32>().map_err(|_| {
+                    GError::from_code(&CANNOT_CONVERT, format!("Could not cast '{}' to type 'Integer'", str_value).as_str(), node.einfo.clone())
+                })?
             }
             _ => {
-                v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::TypeError, format!("Invalid attempted type cast to type 'Integer'").as_str(), node.einfo.clone()));
-                v.errorstack.borrow().terminate_gs();
+                return Err(GError::from_code(&WRONG_ARGUMENT_TYPE, format!("Invalid attempted type cast to type 'Integer'").as_str(), node.einfo.clone()));
             }
-        }
-        ASTNode::new(AST::INT{int_value : numval}, node.einfo.clone())
+        };
+        Ok(ASTNode::new(AST::INT{int_value : numval}, node.einfo.clone()))
     }
 }
 ///GScript: converts AST_STRING to AST_FLOAT
-pub fn std_func_to_float(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> ASTNode {
+pub fn std_func_to_float(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
     if args.len() != 1 {
-        v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::FunctionError, format!("Function 'to_float' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
-        return ASTNode::new_noop();
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'to_float' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
     } else {
-        let arg = v.visit(&args[0]);
-        let mut numval= 0.0;
-        match &arg.kind {
+        let arg = v.visit(&args[0])?;
+        let numval = match &arg.kind {
             AST::STRING{str_value} => {
-                numval = str_value.clone().parse::<f32>().unwrap_or_else(|_| {
-                    v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::TypeError, format!("Could not cast '{}' to type 'Float'", str_value).as_str(), node.einfo.clone()));
-                    v.errorstack.borrow().terminate_gs();
-                    0.0
-                });
-                
+                str_value.clone().parse::<f32>().map_err(|_| {
+                    GError::from_code(&CANNOT_CONVERT, format!("Could not cast '{}' to type 'Float'", str_value).as_str(), node.einfo.clone())
+                })?
             }
             _ => {
-                v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::TypeError, format!("Invalid attempted type cast to type 'Float'").as_str(), node.einfo.clone()));
-                v.errorstack.borrow().terminate_gs();
+                return Err(GError::from_code(&WRONG_ARGUMENT_TYPE, format!("Invalid attempted type cast to type 'Float'").as_str(), node.einfo.clone()));
             }
-        }
-        ASTNode::new(AST::FLOAT{float_value : numval}, node.einfo.clone())
+        };
+        Ok(ASTNode::new(AST::FLOAT{float_value : numval}, node.einfo.clone()))
     }
 }
 ///GScript: Generates random integer between provided range, inclusive
-pub fn std_func_random_int(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> ASTNode {
+pub fn std_func_random_int(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
     if args.len() != 2 {
-        v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::FunctionError, format!("Function 'random_int' requires 2 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
-        return ASTNode::new_noop();
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'random_int' requires 2 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
     } else {
-        let arg1 = v.visit(&args[0]);
-        let arg2 = v.visit(&args[1]);
+        let arg1 = v.visit(&args[0])?;
+        let arg2 = v.visit(&args[1])?;
 
         match (arg1.kind, arg2.kind) {
             (AST::INT{ int_value: n1}, AST::INT{ int_value : n2}) => {
                 let mut rng = rand::thread_rng();
                 let numval = rng.gen_range(n1..=n2);
-                ASTNode::new(AST::INT{int_value : numval }, node.einfo.clone())
+                Ok(ASTNode::new(AST::INT{int_value : numval }, node.einfo.clone()))
             },
             _ => {
-                v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::TypeError, format!("Invalid type(s) to function 'random_integer': Expected (Integer, Integer)").as_str(), node.einfo.clone()));
-                ASTNode::new_noop()
+                v.report(GError::from_code(&WRONG_ARGUMENT_TYPE, format!("Invalid type(s) to function 'random_integer': Expected (Integer, Integer)").as_str(), node.einfo.clone()));
+                Ok(ASTNode::new_noop())
             }
         }
     }
 }
 #[deprecated]
-pub fn std_func_length(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> ASTNode {
+pub fn std_func_length(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
     if args.len() != 1 {
-        v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::FunctionError, format!("Function 'length' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
-        return ASTNode::new_noop();
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'length' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
     } else {
-        let arg1 = v.visit(&args[0]);
+        let arg1 = v.visit(&args[0])?;
 
         match arg1.kind {
             AST::LIST{contents} => {
-                ASTNode::new(AST::INT{int_value: contents.len() as i32}, node.einfo.clone())
+                Ok(ASTNode::new(AST::INT{int_value: contents.len() as i32}, node.einfo.clone()))
             },
             AST::STRING{str_value} => {
-                ASTNode::new(AST::INT{int_value: str_value.len() as i32}, node.einfo.clone())
+                Ok(ASTNode::new(AST::INT{int_value: str_value.len() as i32}, node.einfo.clone()))
             }
             _ => {
-                ASTNode::new(AST::INT{int_value: 0}, node.einfo.clone())
+                Ok(ASTNode::new(AST::INT{int_value: 0}, node.einfo.clone()))
             }
         }
     }
 }
-pub fn std_string_func_length(_v:&mut Visitor, s: &ASTNode) -> ASTNode {
+pub fn std_string_func_length(_v:&mut Visitor, s: &ASTNode) -> Result<ASTNode, GError> {
     if let AST::STRING{str_value} = &s.kind {
-        ASTNode::new(AST::INT{int_value: str_value.chars().count() as i32}, s.einfo.clone())
+        Ok(ASTNode::new(AST::INT{int_value: str_value.chars().count() as i32}, s.einfo.clone()))
     } else {
-        ASTNode::new(AST::INT{int_value: 0}, s.einfo.clone())
+        Ok(ASTNode::new(AST::INT{int_value: 0}, s.einfo.clone()))
     }
 }
 //todo: error handling
-pub fn std_string_func_char(v : &mut Visitor, s: &ASTNode, n: &ASTNode) -> ASTNode {
-    let n = v.visit(n);
+pub fn std_string_func_char(v : &mut Visitor, s: &ASTNode, n: &ASTNode) -> Result<ASTNode, GError> {
+    let n = v.visit(n)?;
     let mut i = 0;
     if let AST::INT{int_value} = &n.kind {
         i = *int_value;
     }
     if let AST::STRING{str_value: strval} = &s.kind {
         let res = strval.chars().nth(i as usize).unwrap_or('\0').to_string();
-        ASTNode::new(AST::STRING{str_value: res}, n.einfo.clone())
+        Ok(ASTNode::new(AST::STRING{str_value: res}, n.einfo.clone()))
     } else {
-        ASTNode::new_noop()
+        Ok(ASTNode::new_noop())
     }
 }
-pub fn std_func_replace(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> ASTNode {
+pub fn std_func_replace(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
     if args.len() != 3 {
-        v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::FunctionError, format!("Function 'replace' requires 3 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
-        return ASTNode::new_noop();
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'replace' requires 3 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
     } else {
-        let arg1 = v.visit(&args[0]);
-        let arg2 = v.visit(&args[1]);
-        let arg3 = v.visit(&args[2]);
+        let arg1 = v.visit(&args[0])?;
+        let arg2 = v.visit(&args[1])?;
+        let arg3 = v.visit(&args[2])?;
        // println!("{:#?} {:#?} {:#?}", arg1.kind.clone(), arg2.kind.clone(), arg3.kind.clone());
         match (arg1.kind, arg2.kind, arg3.kind) {
             (AST::STRING{str_value}, AST::INT{int_value}, AST::STRING{str_value: char_value}) => {
                 let mut strval : Vec<char> = str_value.clone().chars().collect();
                 strval[int_value as usize] = char_value.chars().nth(0).unwrap();
-                ASTNode::new(AST::STRING{str_value: strval.into_iter().collect()}, node.einfo.clone())
+                Ok(ASTNode::new(AST::STRING{str_value: strval.into_iter().collect()}, node.einfo.clone()))
             }
             _ => {
                 
-                v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::TypeError, format!("Invalid type(s) to function 'replace': Expected (String, Integer, Character)").as_str(), node.einfo.clone()));
-                ASTNode::new_noop()
+                v.report(GError::from_code(&WRONG_ARGUMENT_TYPE, format!("Invalid type(s) to function 'replace': Expected (String, Integer, Character)").as_str(), node.einfo.clone()));
+                Ok(ASTNode::new_noop())
             }
         }
     }
 }
 #[allow(non_snake_case)]
-pub fn std_func_PRIMITIVE(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> ASTNode {
+pub fn std_func_PRIMITIVE(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
     if args.len() != 1 {
-        v.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::FunctionError, format!("Function '_PRIMITIVE' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
-        return ASTNode::new_noop();
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function '_PRIMITIVE' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
     } else {
         match &args[0].kind {
-            AST::STRING{ .. } | AST::INT{..} | AST::FLOAT{..} => args[0].clone(),
-            _ => ASTNode::new_noop()
+            AST::STRING{ .. } | AST::INT{..} | AST::FLOAT{..} => Ok(args[0].clone()),
+            _ => Ok(ASTNode::new_noop())
+        }
+    }
+}
+///GScript: returns a shallow copy of a list or object, the elements or properties themselves are still shared
+pub fn std_func_copy(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
+    if args.len() != 1 {
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'copy' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
+    }
+    let arg = v.visit(&args[0])?;
+    match &arg.kind {
+        AST::LIST{contents} => {
+            Ok(ASTNode::new(AST::LIST{contents: contents.iter().map(|c| Rc::new(RefCell::new(c.borrow().clone()))).collect()}, arg.einfo.clone()))
+        }
+        AST::OBJECT{class_name, scope} => {
+            Ok(ASTNode::new(AST::OBJECT{class_name: class_name.clone(), scope: Scope::deep_clone(Some(scope.clone())).unwrap()}, arg.einfo.clone()))
+        }
+        _ => Ok(arg)
+    }
+}
+///GScript: returns a copy of a list or object that shares nothing with the original
+pub fn std_func_deep_copy(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
+    if args.len() != 1 {
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'deep_copy' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
+    }
+    let arg = v.visit(&args[0])?;
+    Ok(deep_copy_node(&arg, &mut HashMap::new()))
+}
+//copied keeps track of the objects that were already copied so that objects referring to each other are copied once
+fn deep_copy_node(node : &ASTNode, copied : &mut HashMap<*const RefCell<Scope>, Rc<RefCell<Scope>>>) -> ASTNode {
+    match &node.kind {
+        AST::LIST{contents} => {
+            ASTNode::new(AST::LIST{contents: contents.iter().map(|c| Rc::new(RefCell::new(deep_copy_node(&c.borrow(), copied)))).collect()}, node.einfo.clone())
+        }
+        AST::OBJECT{class_name, scope} => {
+            if let Some(new_scope) = copied.get(&Rc::as_ptr(scope)) {
+                return ASTNode::new(AST::OBJECT{class_name: class_name.clone(), scope: new_scope.clone()}, node.einfo.clone());
+            }
+            let new_scope = Scope::deep_clone(Some(scope.clone())).unwrap();
+            copied.insert(Rc::as_ptr(scope), new_scope.clone());
+            let vars : Vec<Rc<RefCell<ASTNode>>> = new_scope.borrow().variables.values().cloned().collect();
+            for var_def in vars {
+                let copy = match &var_def.borrow().kind {
+                    AST::VAR_DEF{name, value} => AST::VAR_DEF{name: name.clone(), value: Box::new(deep_copy_node(value, copied))},
+                    _ => continue
+                };
+                var_def.borrow_mut().kind = copy;
+            }
+            ASTNode::new(AST::OBJECT{class_name: class_name.clone(), scope: new_scope}, node.einfo.clone())
+        }
+        _ => node.clone()
+    }
+}
+///GScript: returns the command line arguments given after the script's file name, as a list of strings
+pub fn std_func_args(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
+    if !args.is_empty() {
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'args' requires 0 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
+    }
+    let contents = v.script_args.iter().map(|a| Rc::new(RefCell::new(ASTNode::new(AST::STRING{str_value: a.clone()}, node.einfo.clone())))).collect();
+    Ok(ASTNode::new(AST::LIST{contents}, node.einfo.clone()))
+}
+///GScript: ends the program with the given exit code, after reporting any errors
+///this unwinds like an error, and whoever is running the script decides how to exit
+pub fn std_func_exit(v : &mut Visitor, node : &ASTNode, args : &Vec<ASTNode>) -> Result<ASTNode, GError> {
+    if args.len() != 1 {
+        v.report(GError::from_code(&WRONG_ARGUMENT_COUNT, format!("Function 'exit' requires 1 argument(s), not {}", args.len()).as_str(), node.einfo.clone()));
+        return Ok(ASTNode::new_noop());
+    }
+    let code = v.visit(&args[0])?;
+    match code.kind {
+        AST::INT{int_value} => Err(GError::exit(int_value, node.einfo.clone())),
+        _ => {
+            v.report(GError::from_code(&WRONG_ARGUMENT_TYPE, "Invalid type to function 'exit': Expected (Integer)", node.einfo.clone()));
+            Ok(ASTNode::new_noop())
         }
     }
 }
\ No newline at end of file
@@ -1,22 +1,63 @@
+use crate::errors::codes::*;
 use crate::errors::error::*;
+use crate::errors::suggest::*;
+use crate::modules::ModuleSource;
 use crate::parsing::ast::*;
+use crate::parsing::parser::Parser;
 use crate::parsing::token::*;
 use crate::scope::*;
 use crate::stdfunc::*;
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::rc::Rc;
 
+//functions implemented in Rust, which are called by name before looking in the scope
+pub const BUILTIN_FUNCTIONS: [&str; 12] = [
+    "write",
+    "read",
+    "ast_debug",
+    "type",
+    "to_int",
+    "to_float",
+    "random_int",
+    "replace",
+    "copy",
+    "deep_copy",
+    "args",
+    "exit",
+];
+
 pub struct Visitor {
     current_scope: Rc<RefCell<Scope>>,
+    //the scope of the main script, which is also where the standard libraries are preloaded
+    global_scope: Rc<RefCell<Scope>>,
+    //scopes of every imported module by canonical path, so each file is only run once
+    //this also keeps them alive, since scopes only hold weak references to their parents
+    modules: HashMap<PathBuf, Rc<RefCell<Scope>>>,
+    //(canonical path, display name) of the files currently being imported, starting with the importing file
+    import_stack: Vec<(PathBuf, String)>,
     pub errorstack: Rc<RefCell<ErrorStack>>,
     keywords: Vec<String>,
-    pub preload: bool,
+    //load the standard libraries from the module search path instead of the copies embedded in the binary
+    pub std_from_disk: bool,
+    //arguments after the script's file name on the command line, returned by args()
+    pub script_args: Vec<String>,
+    //(function name, call site) of every function call being run, innermost last
+    call_stack: Vec<(String, ErrorInfo)>,
+    //(object scope, property name) of the getters and setters being run, so that 'self.name'
+    //inside them uses the stored property like a bare 'name' does, instead of calling them again
+    accessors: Vec<(Rc<RefCell<Scope>>, String)>,
 }
 impl Visitor {
     pub fn new(errorstack: Rc<RefCell<ErrorStack>>) -> Visitor {
+        let global_scope = Rc::new(RefCell::new(Scope::new(None)));
         Visitor {
             errorstack,
-            current_scope: Rc::new(RefCell::new(Scope::new(None))),
+            current_scope: global_scope.clone(),
+            global_scope,
+            modules: HashMap::new(),
+            import_stack: Vec::new(),
             keywords: vec![
                 "assign",
                 "funct",
@@ -30,14 +71,21 @@ impl Visitor {
                 "true",
                 "false",
                 "import",
+                "private",
+                "static",
+                "self",
+                "export",
             ]
             .iter()
             .map(|x| x.to_string())
             .collect(),
-            preload: false,
+            std_from_disk: false,
+            script_args: Vec::new(),
+            call_stack: Vec::new(),
+            accessors: Vec::new(),
         }
     }
-    pub fn visit(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             //maybe change this to a deref of node?
             AST::STRING { .. }
@@ -46,7 +94,7 @@ impl Visitor {
             | AST::BOOL { .. }
             | AST::BREAK
             | AST::OBJECT { .. } => {
-                return node.clone();
+                return Ok(node.clone());
             }
             AST::BINOP { .. } => {
                 return self.visit_binop(node);
@@ -73,7 +121,7 @@ impl Visitor {
                 return self.visit_variable(node);
             }
             AST::FUNC_CALL { .. } => {
-                return self.visit_function_call(node, None);
+                return self.visit_function_call(node);
             }
             AST::FUNC_DEF { .. } => {
                 return self.visit_function_definition(node);
@@ -102,16 +150,19 @@ impl Visitor {
             AST::IMPORT { .. } => {
                 return self.visit_import(node);
             }
+            AST::EXPORT { .. } => {
+                return self.visit_export(node);
+            }
             AST::COMPOUND { compound_value } => {
                 for ast in compound_value {
-                    let res = self.visit(ast);
+                    let res = self.visit(ast)?;
                     if let AST::RETURN { .. } | AST::BREAK = res.kind {
-                        return res;
+                        return Ok(res);
                     }
                 }
-                return ASTNode::new_noop();
+                return Ok(ASTNode::new_noop());
             }
-            _ => return ASTNode::new_noop(),
+            _ => return Ok(ASTNode::new_noop()),
         }
     }
     //visit_binop helper function
@@ -133,35 +184,35 @@ impl Visitor {
             }
         }
     }
-    pub fn visit_binop(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_binop(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::BINOP { left, op, right } => {
-                let nleft = self.visit(left);
-                let nright = self.visit(right);
+                let nleft = self.visit(left)?;
+                let nright = self.visit(right)?;
                 match op {
                     TokenType::PLS => {
                         if matches!(nleft.kind, AST::INT { .. })
                             && matches!(nright.kind, AST::INT { .. })
                         {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::INT {
                                     int_value: self.node_to_int(&nleft).unwrap()
                                         + self.node_to_int(&nright).unwrap(),
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
-                                return ASTNode::new_noop();
+                                return Ok(ASTNode::new_noop());
                             } else {
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::FLOAT {
                                         float_value: fleft.unwrap() + fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
@@ -169,25 +220,25 @@ impl Visitor {
                         if matches!(nleft.kind, AST::INT { .. })
                             && matches!(nright.kind, AST::INT { .. })
                         {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::INT {
                                     int_value: self.node_to_int(&nleft).unwrap()
                                         - self.node_to_int(&nright).unwrap(),
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
-                                return ASTNode::new_noop();
+                                return Ok(ASTNode::new_noop());
                             } else {
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::FLOAT {
                                         float_value: fleft.unwrap() - fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
@@ -195,25 +246,25 @@ impl Visitor {
                         if matches!(nleft.kind, AST::INT { .. })
                             && matches!(nright.kind, AST::INT { .. })
                         {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::INT {
                                     int_value: self.node_to_int(&nleft).unwrap()
                                         * self.node_to_int(&nright).unwrap(),
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
-                                return ASTNode::new_noop();
+                                return Ok(ASTNode::new_noop());
                             } else {
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::FLOAT {
                                         float_value: fleft.unwrap() * fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
@@ -223,47 +274,37 @@ impl Visitor {
                         {
                             let fright = self.node_to_int(&nright).unwrap();
                             if fright == 0 {
-                                self.errorstack
-                                    .borrow_mut()
-                                    .errors
-                                    .push(GError::new_from_tok(
-                                        ETypes::DivideByZeroError,
-                                        "Cannot divide by zero",
-                                        nright.einfo.clone(),
-                                    ));
-                                self.errorstack.borrow().terminate_gs();
-                                return ASTNode::new_noop();
+                                return Err(GError::from_code(
+                                    &DIVIDE_BY_ZERO,
+                                    "Cannot divide by zero",
+                                    nright.einfo.clone(),
+                                ));
                             }
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::INT {
                                     int_value: self.node_to_int(&nleft).unwrap() / fright,
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
-                                return ASTNode::new_noop();
+                                return Ok(ASTNode::new_noop());
                             } else {
                                 if fright.unwrap() == 0.0 {
-                                    self.errorstack
-                                        .borrow_mut()
-                                        .errors
-                                        .push(GError::new_from_tok(
-                                            ETypes::DivideByZeroError,
-                                            "Cannot divide by zero",
-                                            nright.einfo.clone(),
-                                        ));
-                                    self.errorstack.borrow().terminate_gs();
-                                    return ASTNode::new_noop();
+                                    return Err(GError::from_code(
+                                        &DIVIDE_BY_ZERO,
+                                        "Cannot divide by zero",
+                                        nright.einfo.clone(),
+                                    ));
                                 }
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::FLOAT {
                                         float_value: fleft.unwrap() / fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
@@ -273,47 +314,37 @@ impl Visitor {
                         {
                             let fright = self.node_to_int(&nright).unwrap();
                             if fright == 0 {
-                                self.errorstack
-                                    .borrow_mut()
-                                    .errors
-                                    .push(GError::new_from_tok(
-                                        ETypes::DivideByZeroError,
-                                        "Cannot divide by zero",
-                                        nright.einfo.clone(),
-                                    ));
-                                self.errorstack.borrow().terminate_gs();
-                                return ASTNode::new_noop();
+                                return Err(GError::from_code(
+                                    &DIVIDE_BY_ZERO,
+                                    "Cannot divide by zero",
+                                    nright.einfo.clone(),
+                                ));
                             }
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::INT {
                                     int_value: self.node_to_int(&nleft).unwrap() % fright,
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
-                                return ASTNode::new_noop();
+                                return Ok(ASTNode::new_noop());
                             } else {
                                 if fright.unwrap() == 0.0 {
-                                    self.errorstack
-                                        .borrow_mut()
-                                        .errors
-                                        .push(GError::new_from_tok(
-                                            ETypes::DivideByZeroError,
-                                            "Cannot divide by zero",
-                                            nright.einfo.clone(),
-                                        ));
-                                    self.errorstack.borrow().terminate_gs();
-                                    return ASTNode::new_noop();
+                                    return Err(GError::from_code(
+                                        &DIVIDE_BY_ZERO,
+                                        "Cannot divide by zero",
+                                        nright.einfo.clone(),
+                                    ));
                                 }
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::FLOAT {
                                         float_value: fleft.unwrap() % fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
@@ -321,54 +352,54 @@ impl Visitor {
                         if matches!(nleft.kind, AST::INT { .. })
                             && matches!(nright.kind, AST::INT { .. })
                         {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::BOOL {
                                     bool_value: self.node_to_int(&nleft).unwrap()
                                         == self.node_to_int(&nright).unwrap(),
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
                                 match (nleft.kind, nright.kind) {
                                     (AST::BOOL { bool_value: x }, AST::BOOL { bool_value: y }) => {
-                                        return ASTNode::new(
+                                        return Ok(ASTNode::new(
                                             AST::BOOL { bool_value: x == y },
                                             node.einfo.clone(),
-                                        );
+                                        ));
                                     }
                                     (
                                         AST::STRING { str_value: x },
                                         AST::STRING { str_value: y },
                                     ) => {
                                         //println!("{} == {}", x, y);
-                                        return ASTNode::new(
+                                        return Ok(ASTNode::new(
                                             AST::BOOL { bool_value: x == y },
                                             node.einfo.clone(),
-                                        );
+                                        ));
                                     }
                                     (
                                         AST::TYPE { type_value: t1 },
                                         AST::TYPE { type_value: t2 },
                                     ) => {
-                                        return ASTNode::new(
+                                        return Ok(ASTNode::new(
                                             AST::BOOL {
                                                 bool_value: t1 == t2,
                                             },
                                             node.einfo.clone(),
-                                        );
+                                        ));
                                     }
-                                    _ => return ASTNode::new_noop(),
+                                    _ => return Ok(ASTNode::new_noop()),
                                 }
                             } else {
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::BOOL {
                                         bool_value: fleft.unwrap() == fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
@@ -376,42 +407,42 @@ impl Visitor {
                         if matches!(nleft.kind, AST::INT { .. })
                             && matches!(nright.kind, AST::INT { .. })
                         {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::BOOL {
                                     bool_value: self.node_to_int(&nleft).unwrap()
                                         != self.node_to_int(&nright).unwrap(),
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
                                 match (nleft.kind, nright.kind) {
                                     (AST::BOOL { bool_value: x }, AST::BOOL { bool_value: y }) => {
-                                        return ASTNode::new(
+                                        return Ok(ASTNode::new(
                                             AST::BOOL { bool_value: x != y },
                                             node.einfo.clone(),
-                                        );
+                                        ));
                                     }
                                     (
                                         AST::STRING { str_value: x },
                                         AST::STRING { str_value: y },
                                     ) => {
-                                        return ASTNode::new(
+                                        return Ok(ASTNode::new(
                                             AST::BOOL { bool_value: x != y },
                                             node.einfo.clone(),
-                                        );
+                                        ));
                                     }
-                                    _ => return ASTNode::new_noop(),
+                                    _ => return Ok(ASTNode::new_noop()),
                                 }
                             } else {
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::BOOL {
                                         bool_value: fleft.unwrap() != fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
@@ -419,25 +450,25 @@ impl Visitor {
                         if matches!(nleft.kind, AST::INT { .. })
                             && matches!(nright.kind, AST::INT { .. })
                         {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::BOOL {
                                     bool_value: self.node_to_int(&nleft).unwrap()
                                         < self.node_to_int(&nright).unwrap(),
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
-                                return ASTNode::new_noop();
+                                return Ok(ASTNode::new_noop());
                             } else {
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::BOOL {
                                         bool_value: fleft.unwrap() < fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
@@ -445,25 +476,25 @@ impl Visitor {
                         if matches!(nleft.kind, AST::INT { .. })
                             && matches!(nright.kind, AST::INT { .. })
                         {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::BOOL {
                                     bool_value: self.node_to_int(&nleft).unwrap()
                                         <= self.node_to_int(&nright).unwrap(),
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
-                                return ASTNode::new_noop();
+                                return Ok(ASTNode::new_noop());
                             } else {
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::BOOL {
                                         bool_value: fleft.unwrap() <= fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
@@ -471,25 +502,25 @@ impl Visitor {
                         if matches!(nleft.kind, AST::INT { .. })
                             && matches!(nright.kind, AST::INT { .. })
                         {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::BOOL {
                                     bool_value: self.node_to_int(&nleft).unwrap()
                                         > self.node_to_int(&nright).unwrap(),
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
-                                return ASTNode::new_noop();
+                                return Ok(ASTNode::new_noop());
                             } else {
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::BOOL {
                                         bool_value: fleft.unwrap() > fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
@@ -497,93 +528,89 @@ impl Visitor {
                         if matches!(nleft.kind, AST::INT { .. })
                             && matches!(nright.kind, AST::INT { .. })
                         {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::BOOL {
                                     bool_value: self.node_to_int(&nleft).unwrap()
                                         >= self.node_to_int(&nright).unwrap(),
                                 },
                                 node.einfo.clone(),
-                            );
+                            ));
                         } else {
                             let fleft = self.node_to_float(&nleft);
                             let fright = self.node_to_float(&nright);
                             if fleft.is_none() || fright.is_none() {
-                                return ASTNode::new_noop();
+                                return Ok(ASTNode::new_noop());
                             } else {
-                                return ASTNode::new(
+                                return Ok(ASTNode::new(
                                     AST::BOOL {
                                         bool_value: fleft.unwrap() >= fright.unwrap(),
                                     },
                                     node.einfo.clone(),
-                                );
+                                ));
                             }
                         }
                     }
                     TokenType::AND => match (nleft.kind, nright.kind) {
                         (AST::BOOL { bool_value: x }, AST::BOOL { bool_value: y }) => {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::BOOL { bool_value: x && y },
                                 node.einfo.clone(),
-                            );
+                            ));
                         }
-                        _ => return ASTNode::new_noop(),
+                        _ => return Ok(ASTNode::new_noop()),
                     },
                     TokenType::OR => match (nleft.kind, nright.kind) {
                         (AST::BOOL { bool_value: x }, AST::BOOL { bool_value: y }) => {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::BOOL { bool_value: x || y },
                                 node.einfo.clone(),
-                            );
+                            ));
                         }
-                        _ => return ASTNode::new_noop(),
+                        _ => return Ok(ASTNode::new_noop()),
                     },
 
-                    _ => return ASTNode::new_noop(),
+                    _ => return Ok(ASTNode::new_noop()),
                 }
             }
-            _ => return ASTNode::new_noop(),
+            _ => return Ok(ASTNode::new_noop()),
         }
     }
-    pub fn visit_unop(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_unop(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::UNOP { op, body } => {
-                let body_val = self.visit(body);
+                let body_val = self.visit(body)?;
                 match (op, &body_val.kind) {
                     (TokenType::MIN, AST::INT { int_value }) => {
-                        return ASTNode::new(
+                        return Ok(ASTNode::new(
                             AST::INT {
                                 int_value: -int_value,
                             },
                             node.einfo.clone(),
-                        );
+                        ));
                     }
                     (TokenType::MIN, AST::FLOAT { float_value }) => {
-                        return ASTNode::new(
+                        return Ok(ASTNode::new(
                             AST::FLOAT {
                                 float_value: -float_value,
                             },
                             node.einfo.clone(),
-                        );
+                        ));
                     }
                     (TokenType::NOT, AST::BOOL { bool_value }) => {
-                        return ASTNode::new(
+                        return Ok(ASTNode::new(
                             AST::BOOL {
                                 bool_value: !bool_value,
                             },
                             node.einfo.clone(),
-                        );
+                        ));
                     }
-                    _ => return ASTNode::new_noop(),
+                    _ => return Ok(ASTNode::new_noop()),
                 }
             }
-            _ => return ASTNode::new_noop(),
+            _ => return Ok(ASTNode::new_noop()),
         }
     }
-    pub fn visit_function_call(
-        &mut self,
-        node: &ASTNode,
-        extern_scope: Option<Rc<RefCell<Scope>>>,
-    ) -> ASTNode {
+    pub fn visit_function_call(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::FUNC_CALL { name, args } => {
                 match name.as_str() {
@@ -596,41 +623,35 @@ impl Visitor {
                     "random_int" => return std_func_random_int(self, node, args),
                     "replace" => return std_func_replace(self, node, args),
                     "_PRIMITIVE" => return std_func_PRIMITIVE(self, node, args),
+                    "copy" => return std_func_copy(self, node, args),
+                    "deep_copy" => return std_func_deep_copy(self, node, args),
+                    "args" => return std_func_args(self, node, args),
+                    "exit" => return std_func_exit(self, node, args),
                     _ => {}
                 }
-                //Interesting how I need to store the borrowed currscope in a local variable
-                //I think it's because since the b_currscope is declared after currscope, it
-                //is dropped first, meaning currscope can then be safely dropped
-                let fdef_option = {
-                    let currscope = self.current_scope.clone();
-                    let b_currscope = currscope.borrow();
-                    b_currscope.resolve_func(name.clone())
-                };
+                let fdef_option =
+                    Scope::resolve_func_owner(self.current_scope.clone(), name.clone());
 
-                if let Some(fdef) = fdef_option {
+                if let Some((fdef, owner)) = fdef_option {
                     match &fdef.kind {
                         AST::FUNC_DEF {
                             name: _,
-                            body: fdef_body,
+                            body: _,
                             args: fdef_args,
                         } => {
                             if args.len() != fdef_args.len() {
                                 //improper args error
-                                self.errorstack
-                                    .borrow_mut()
-                                    .errors
-                                    .push(GError::new_from_tok(
-                                        ETypes::FunctionError,
-                                        format!(
-                                            "Function '{}' requires {} argument(s), not {}",
-                                            name,
-                                            fdef_args.len(),
-                                            args.len()
-                                        )
-                                        .as_str(),
-                                        node.einfo.clone(),
-                                    ));
-                                return ASTNode::new_noop();
+                                return Err(GError::from_code(
+                                    &WRONG_ARGUMENT_COUNT,
+                                    format!(
+                                        "Function '{}' requires {} argument(s), not {}",
+                                        name,
+                                        fdef_args.len(),
+                                        args.len()
+                                    )
+                                    .as_str(),
+                                    node.einfo.clone(),
+                                ));
                             }
                             // -- FIXED --
                             // by implementing Scope::get_root_scope
@@ -638,121 +659,161 @@ impl Visitor {
                             //setting self.current_scope as the parent DOES NOT WORK in recursive scenarios
                             //solution: set the parent as some kind of global scope
                             // !! END ISSUE !!
+                            // -- UPDATE --
+                            // the parent is now the scope the function was defined in, which is the
+                            // global scope for regular functions and the object's scope for methods
 
-                            let func_scope = match extern_scope {
-                                Some(s) => Rc::new(RefCell::new(Scope::new(Some(s.clone())))),
-                                None => Rc::new(RefCell::new(Scope::new(Some(
-                                    Scope::get_root_scope(self.current_scope.clone()),
-                                )))),
-                            };
-                            //println!("func_scope: {:#?}", func_scope);
-                            for (argdef, arg) in fdef_args.iter().zip(args.iter()) {
-                                let arg_val = self.visit(arg);
-
-                                if let AST::VAR_DEF {
-                                    name: argdef_name, ..
-                                } = &argdef.kind
-                                {
-                                    if let Err(s) = func_scope.borrow_mut().add_var(&ASTNode::new(
-                                        AST::VAR_DEF {
-                                            name: argdef_name.clone(),
-                                            value: Box::new(arg_val),
-                                        },
-                                        arg.einfo.clone(),
-                                    )) {
-                                        println!("{}", s);
-                                    }
-                                } else {
-                                    //this should never happen...
-                                    eprintln!("func argdef error (should not ever be reached)");
-                                    return ASTNode::new_noop();
-                                }
-                            }
-                            let cscope = self.current_scope.clone();
-                            self.current_scope = func_scope;
-                            let res = self.visit(&fdef_body);
-                            self.current_scope = cscope;
-                            if let AST::RETURN { value } = res.kind {
-                                *value
-                            } else {
-                                res
-                            }
+                            //a method called by its bare name from another method of the same object keeps 'self'
+                            let self_obj = self.resolve_self().filter(|obj| {
+                                matches!(&obj.kind, AST::OBJECT { scope, .. } if Rc::ptr_eq(scope, &owner))
+                            });
+                            let arg_vals = args
+                                .iter()
+                                .map(|arg| self.visit(arg))
+                                .collect::<Result<_, _>>()?;
+                            self.invoke_function(&fdef, arg_vals, owner, self_obj, &node.einfo)
                         }
-                        _ => ASTNode::new_noop(),
+                        _ => Ok(ASTNode::new_noop()),
                     }
                 } else {
                     //function is not defined error
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::FunctionError,
-                            format!("Function '{}' does not exist in the current scope", name)
-                                .as_str(),
-                            node.einfo.clone(),
+                    let funcs = self.current_scope.borrow().visible_funcs();
+                    let message = with_suggestion(
+                        format!("Function '{}' does not exist in the current scope", name),
+                        name,
+                        funcs.iter().map(String::as_str).chain(BUILTIN_FUNCTIONS),
+                    );
+                    self.report(GError::from_code(
+                        &UNDEFINED_FUNCTION,
+                        message.as_str(),
+                        node.einfo.clone(),
+                    ));
+                    Ok(ASTNode::new_noop())
+                }
+            }
+            _ => Ok(ASTNode::new_noop()),
+        }
+    }
+    //runs the body of a function definition in a new scope whose parent is 'parent',
+    //binding the already evaluated 'arg_vals' to its parameters
+    //methods also get 'self' bound to the object they were called on
+    pub fn invoke_function(
+        &mut self,
+        fdef: &ASTNode,
+        arg_vals: Vec<ASTNode>,
+        parent: Rc<RefCell<Scope>>,
+        self_obj: Option<ASTNode>,
+        call_site: &ErrorInfo,
+    ) -> Result<ASTNode, GError> {
+        match &fdef.kind {
+            AST::FUNC_DEF {
+                name,
+                body: fdef_body,
+                args: fdef_args,
+            } => {
+                //methods are shown with their blueprint in stack traces
+                let frame = match self_obj.as_ref().map(|obj| &obj.kind) {
+                    Some(AST::OBJECT { class_name, .. }) if class_name != "import object" => {
+                        format!("{}.{}", class_name, name)
+                    }
+                    _ => name.clone(),
+                };
+                let func_scope = Rc::new(RefCell::new(Scope::new(Some(parent))));
+                //println!("func_scope: {:#?}", func_scope);
+                if let Some(obj) = self_obj {
+                    func_scope.borrow_mut().define_var(ASTNode::new(
+                        AST::VAR_DEF {
+                            name: "self".to_string(),
+                            value: Box::new(obj.clone()),
+                        },
+                        obj.einfo.clone(),
+                    ));
+                }
+                for (argdef, arg_val) in fdef_args.iter().zip(arg_vals) {
+                    if let AST::VAR_DEF {
+                        name: argdef_name, ..
+                    } = &argdef.kind
+                    {
+                        let e = arg_val.einfo.clone();
+                        //parameters shadow any variables with the same name in the parent scopes,
+                        //like the properties of an object
+                        func_scope.borrow_mut().define_var(ASTNode::new(
+                            AST::VAR_DEF {
+                                name: argdef_name.clone(),
+                                value: Box::new(arg_val),
+                            },
+                            e,
                         ));
-                    ASTNode::new_noop()
+                    } else {
+                        //this should never happen...
+                        eprintln!("func argdef error (should not ever be reached)");
+                        return Ok(ASTNode::new_noop());
+                    }
+                }
+                let cscope = self.current_scope.clone();
+                self.current_scope = func_scope;
+                self.call_stack.push((frame, call_site.clone()));
+                //the innermost call that an error passes through records the whole stack
+                let res = self
+                    .visit(fdef_body)
+                    .map_err(|e| e.with_trace(&self.call_stack));
+                self.call_stack.pop();
+                self.current_scope = cscope;
+                let res = res?;
+                if let AST::RETURN { value } = res.kind {
+                    Ok(*value)
+                } else {
+                    Ok(res)
                 }
             }
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
-    pub fn visit_return(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_return(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
-            AST::RETURN { value } => ASTNode::new(
+            AST::RETURN { value } => Ok(ASTNode::new(
                 AST::RETURN {
-                    value: Box::new(self.visit(&value)),
+                    value: Box::new(self.visit(&value)?),
                 },
                 node.einfo.clone(),
-            ),
-            _ => ASTNode::new_noop(),
+            )),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
-    pub fn visit_function_definition(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_function_definition(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::FUNC_DEF { name, .. } => {
                 if self.keywords.contains(name) {
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::FunctionDefinitionError,
-                            "Illegal use of keyword for function definition",
-                            node.einfo.clone(),
-                        ));
-                    return ASTNode::new_noop();
+                    self.report(GError::from_code(
+                        &KEYWORD_AS_FUNCTION,
+                        "Illegal use of keyword for function definition",
+                        node.einfo.clone(),
+                    ));
+                    return Ok(ASTNode::new_noop());
                 }
                 if let Err(s) = self.current_scope.borrow_mut().add_func(node) {
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::FunctionDefinitionError,
-                            s.as_str(),
-                            node.einfo.clone(),
-                        ));
-                    self.errorstack.borrow().terminate_gs();
+                    return Err(GError::from_code(
+                        &FUNCTION_EXISTS,
+                        s.as_str(),
+                        node.einfo.clone(),
+                    ));
                 }
-                node.clone()
+                Ok(node.clone())
             }
-            _ => return ASTNode::new_noop(),
+            _ => return Ok(ASTNode::new_noop()),
         }
     }
-    pub fn visit_variable_definition(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_variable_definition(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::VAR_DEF { name, value } => {
                 if self.keywords.contains(name) {
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::VariableDefinitionError,
-                            "Illegal use of keyword for variable definition",
-                            node.einfo.clone(),
-                        ));
-                    return ASTNode::new_noop();
+                    return Err(GError::from_code(
+                        &KEYWORD_AS_VARIABLE,
+                        "Illegal use of keyword for variable definition",
+                        node.einfo.clone(),
+                    ));
                 }
-                let val = self.visit(value);
+                let val = self.visit(value)?;
                 // println!("{:#?}", val);
                 let var_def = ASTNode::new(
                     AST::VAR_DEF {
@@ -764,26 +825,22 @@ impl Visitor {
                 let mut thingy = self.current_scope.borrow_mut();
                 let res = thingy.add_var(&var_def);
                 if let Err(s) = res {
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::VariableDefinitionError,
-                            s.as_str(),
-                            node.einfo.clone(),
-                        ));
-                    self.errorstack.borrow().terminate_gs();
+                    return Err(GError::from_code(
+                        &VARIABLE_EXISTS,
+                        s.as_str(),
+                        node.einfo.clone(),
+                    ));
                 }
                 //println!("{:#?}", self.current_scope.borrow().resolve_var("a".to_string()));
-                var_def
+                Ok(var_def)
             }
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
-    pub fn visit_variable_reassign(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_variable_reassign(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::VAR_REASSIGN { name, value } => {
-                let val = self.visit(value);
+                let val = self.visit(value)?;
                 let var_def = ASTNode::new(
                     AST::VAR_DEF {
                         name: name.clone(),
@@ -796,64 +853,51 @@ impl Visitor {
                     .borrow_mut()
                     .set_var(name.clone(), &var_def);
                 if let Err(s) = res {
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::VariableDefinitionError,
-                            s.as_str(),
-                            node.einfo.clone(),
-                        ));
-                    self.errorstack.borrow().terminate_gs();
+                    return Err(GError::from_code(
+                        &ASSIGN_TO_UNDEFINED,
+                        s.as_str(),
+                        node.einfo.clone(),
+                    ));
                 }
-                var_def
+                Ok(var_def)
             }
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
     // -- ISSUE --
     // calling 'clone' on node DOESN'T DEEP COPY all the fields if the fields are Rc RefCell
     // so cloning an object will not clone its scope or all the properties/methods within that scope
-    pub fn visit_variable(&mut self, node: &ASTNode) -> ASTNode {
+    // -- UPDATE --
+    // this is now relied on: objects and lists are references, so the clone shares the object's
+    // scope and the list's elements with the variable. copy() and deep_copy() make actual copies
+    pub fn visit_variable(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::VAR { name } => {
                 if let Some(var_def) = self.current_scope.borrow().resolve_var(name.to_string()) {
                     match &var_def.borrow().kind {
                         AST::VAR_DEF { name: _, value } => {
-                            if let AST::OBJECT { class_name, scope } = &value.kind {
-                                // println!("{:#?}", scope.borrow().parent.as_ref().unwrap().borrow().variables);
-                                // println!("{:#?}", scope);
-                                let scope = Scope::deep_clone(Some(scope.clone())).unwrap();
-
-                                return ASTNode::new(
-                                    AST::OBJECT {
-                                        class_name: class_name.clone(),
-                                        scope,
-                                    },
-                                    node.einfo.clone(),
-                                );
-                            }
-                            return *value.clone();
+                            return Ok(*value.clone());
                         }
-                        _ => return ASTNode::new_noop(),
+                        _ => return Ok(ASTNode::new_noop()),
                     }
                 } else {
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::IdentifierError,
-                            format!("Variable '{}' does not exist in the current scope", name)
-                                .as_str(),
-                            node.einfo.clone(),
-                        ));
-                    return ASTNode::new_noop();
+                    let vars = self.current_scope.borrow().visible_vars();
+                    let message = with_suggestion(
+                        format!("Variable '{}' does not exist in the current scope", name),
+                        name,
+                        vars.iter().map(String::as_str),
+                    );
+                    Err(GError::from_code(
+                        &UNDEFINED_VARIABLE,
+                        message.as_str(),
+                        node.einfo.clone(),
+                    ))
                 }
             }
-            _ => return ASTNode::new_noop(),
+            _ => return Ok(ASTNode::new_noop()),
         }
     }
-    pub fn visit_if(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_if(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::IF {
                 conditions,
@@ -861,46 +905,43 @@ impl Visitor {
                 else_body,
             } => {
                 for (cond, body) in conditions.iter().zip(bodies.iter()) {
-                    let cond_val = self.visit(cond);
+                    let cond_val = self.visit(cond)?;
                     match &cond_val.kind {
                         AST::BOOL { bool_value } => {
                             if *bool_value {
-                                let res = self.visit(body);
+                                let res = self.visit(body)?;
                                 if let AST::RETURN { .. } | AST::BREAK = res.kind {
-                                    return res;
+                                    return Ok(res);
                                 } else {
-                                    return ASTNode::new_noop();
+                                    return Ok(ASTNode::new_noop());
                                 }
                             }
                         }
                         _ => {
-                            self.errorstack
-                                .borrow_mut()
-                                .errors
-                                .push(GError::new_from_tok(
-                                    ETypes::ConditionalError,
-                                    "Expected conditional expression",
-                                    cond.einfo.clone(),
-                                ));
-                            return ASTNode::new_noop();
+                            self.report(GError::from_code(
+                                &EXPECTED_CONDITION,
+                                "Expected conditional expression",
+                                cond.einfo.clone(),
+                            ));
+                            return Ok(ASTNode::new_noop());
                         }
                     }
                 }
                 if let Some(b) = else_body {
-                    let res = self.visit(&b);
+                    let res = self.visit(&b)?;
                     if let AST::RETURN { .. } | AST::BREAK = res.kind {
-                        return res;
+                        return Ok(res);
                     } else {
-                        return ASTNode::new_noop();
+                        return Ok(ASTNode::new_noop());
                     }
                 } else {
-                    return ASTNode::new_noop();
+                    return Ok(ASTNode::new_noop());
                 }
             }
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
-    pub fn visit_while(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_while(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::WHILE { condition, body } => {
                 // -- ISSUE --
@@ -911,31 +952,39 @@ impl Visitor {
                 // Take the memory of the parent of the temporary scope to reuse it on future iterations
                 //kind of works for now but it might be a better idea to make parent an Rc Refcell reference instead of owned
                 let origin = self.current_scope.clone();
-                loop {
-                    self.current_scope = Rc::new(RefCell::new(Scope::new(Some(origin.clone()))));
-                    let cond = self.visit(condition);
-                    let cond_res = match cond.kind {
-                        AST::BOOL { bool_value } => bool_value,
-                        _ => {
-                            return ASTNode::new_noop();
-                        } //invalid conditional expression error
-                    };
-                    if !cond_res {
-                        break;
-                    }
-                    let res = self.visit(body);
-                    if let AST::RETURN { .. } = res.kind {
-                        self.current_scope = origin;
-                        return res;
-                    } else if let AST::BREAK = res.kind {
-                        self.current_scope = origin;
-                        return ASTNode::new_noop();
-                    }
-                }
+                let res = self.run_loop(condition, body, &origin);
                 self.current_scope = origin;
-                ASTNode::new_noop()
+                res
+            }
+            _ => return Ok(ASTNode::new_noop()),
+        }
+    }
+    //runs the iterations of a while loop, each in a new scope inside 'origin'
+    //the caller goes back to 'origin' afterwards, also when an iteration fails
+    fn run_loop(
+        &mut self,
+        condition: &ASTNode,
+        body: &ASTNode,
+        origin: &Rc<RefCell<Scope>>,
+    ) -> Result<ASTNode, GError> {
+        loop {
+            self.current_scope = Rc::new(RefCell::new(Scope::new(Some(origin.clone()))));
+            let cond = self.visit(condition)?;
+            let cond_res = match cond.kind {
+                AST::BOOL { bool_value } => bool_value,
+                _ => {
+                    return Ok(ASTNode::new_noop());
+                } //invalid conditional expression error
+            };
+            if !cond_res {
+                return Ok(ASTNode::new_noop());
+            }
+            let res = self.visit(body)?;
+            if let AST::RETURN { .. } = res.kind {
+                return Ok(res);
+            } else if let AST::BREAK = res.kind {
+                return Ok(ASTNode::new_noop());
             }
-            _ => return ASTNode::new_noop(),
         }
     }
     pub fn node_to_string(&mut self, node: &ASTNode) -> String {
@@ -975,8 +1024,7 @@ impl Visitor {
                     s.push('"');
                     match &vardef.borrow().kind {
                         AST::VAR_DEF { name: _, value } => {
-                            let visited = self.visit(value);
-                            s.push_str(&self.node_to_string(&visited));
+                            s.push_str(&self.node_to_string(value));
                         }
                         _ => {}
                     }
@@ -1025,89 +1073,78 @@ impl Visitor {
             _ => format!("undefined: \n{:#?}", node).to_string(),
         }
     }
-    pub fn visit_list(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_list(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
-            AST::LIST { contents } => ASTNode::new(
+            AST::LIST { contents } => Ok(ASTNode::new(
                 AST::LIST {
                     contents: contents
                         .iter()
-                        .map(|x| Rc::new(RefCell::new(self.visit(&x.borrow()))))
-                        .collect(),
+                        .map(|x| Ok(Rc::new(RefCell::new(self.visit(&x.borrow())?))))
+                        .collect::<Result<_, GError>>()?,
                 },
                 node.einfo.clone(),
-            ),
-            _ => ASTNode::new_noop(),
+            )),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
-    pub fn visit_index(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_index(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::INDEX { target, indices } => {
-                let mut combined_target = self.visit(target);
+                let mut combined_target = self.visit(target)?;
                 for ind in indices {
-                    let ind = self.visit(ind);
+                    let ind = self.visit(ind)?;
                     let ind_i: i32;
                     match ind.kind {
                         AST::INT { int_value } => ind_i = int_value,
                         _ => {
                             //index is not a number error
-                            self.errorstack
-                                .borrow_mut()
-                                .errors
-                                .push(GError::new_from_tok(
-                                    ETypes::ListError,
-                                    "Expected integer to index list",
-                                    ind.einfo.clone(),
-                                ));
-                            return ASTNode::new_noop();
+                            self.report(GError::from_code(
+                                &INDEX_NOT_INTEGER,
+                                "Expected integer to index list",
+                                ind.einfo.clone(),
+                            ));
+                            return Ok(ASTNode::new_noop());
                         }
                     }
                     match combined_target.kind {
                         AST::LIST { contents } => {
                             if ind_i < 0 || ind_i as usize >= contents.len() {
-                                self.errorstack
-                                    .borrow_mut()
-                                    .errors
-                                    .push(GError::new_from_tok(
-                                        ETypes::ListError,
-                                        format!(
-                                            "Index {} is out of bounds for list of length {}",
-                                            ind_i,
-                                            contents.len()
-                                        )
-                                        .as_str(),
-                                        ind.einfo.clone(),
-                                    ));
-                                self.errorstack.borrow().terminate_gs();
+                                return Err(GError::from_code(
+                                    &INDEX_OUT_OF_BOUNDS,
+                                    format!(
+                                        "Index {} is out of bounds for list of length {}",
+                                        ind_i,
+                                        contents.len()
+                                    )
+                                    .as_str(),
+                                    ind.einfo.clone(),
+                                ));
                             }
                             combined_target = contents[ind_i as usize].borrow().clone()
                         }
                         AST::STRING { str_value: old } => {
-                            return ASTNode::new(
+                            return Ok(ASTNode::new(
                                 AST::STRING {
                                     str_value: String::from(
                                         old.chars().nth(ind_i as usize).unwrap(),
                                     ),
                                 },
                                 node.einfo.clone(),
-                            )
+                            ))
                         }
                         _ => {
                             //target is not a list error
-                            self.errorstack
-                                .borrow_mut()
-                                .errors
-                                .push(GError::new_from_tok(
-                                    ETypes::ListError,
-                                    "Indexed target is not a list",
-                                    combined_target.einfo.clone(),
-                                ));
-                            return ASTNode::new_noop();
+                            return Err(GError::from_code(
+                                &NOT_A_LIST,
+                                "Indexed target is not a list",
+                                combined_target.einfo.clone(),
+                            ));
                         }
                     }
                 }
-                combined_target
+                Ok(combined_target)
             }
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
     // -- ISSUE --
@@ -1115,58 +1152,65 @@ impl Visitor {
     // to enabe getting a mutable reference to an inner element
     // -- SOLUTION --
     // did this
-    pub fn visit_obj_index(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_obj_index(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::OBJECT_INDEX { object, property } => {
-                let obj = self.visit(object);
+                let obj = match self.resolve_static_object(object) {
+                    Some(statics) => statics,
+                    None => self.visit_receiver(object)?,
+                };
                 match &obj.kind {
                     AST::OBJECT { class_name, scope } => {
                         //println!("{:#?}", scope);
+                        self.check_private_access(class_name, scope, property)?;
                         match &property.kind {
                             AST::VAR { name } => {
+                                //reading a property with a getter calls the getter instead
+                                if let Some(getter) = self.accessor(scope, name, false) {
+                                    return self.invoke_accessor(
+                                        &getter,
+                                        vec![],
+                                        scope,
+                                        obj.clone(),
+                                        name,
+                                        &property.einfo,
+                                    );
+                                }
                                 if let Some(val) = scope.borrow().resolve_var(name.clone()) {
                                     if let AST::VAR_DEF { name: _, value } = &val.borrow().kind {
-                                        return *value.clone();
+                                        return Ok(*value.clone());
                                     }
-                                    val.borrow().clone()
+                                    Ok(val.borrow().clone())
                                 } else {
                                     //property does not exist error
-                                    self.errorstack
-                                        .borrow_mut()
-                                        .errors
-                                        .push(GError::new_from_tok(
-                                            ETypes::BlueprintError,
-                                            format!(
-                                                "Property '{}' does not exist on blueprint '{}'",
-                                                name.clone(),
-                                                class_name.clone()
-                                            )
-                                            .as_str(),
-                                            property.einfo.clone(),
-                                        ));
-                                    ASTNode::new_noop()
+                                    let message = with_suggestion(
+                                        format!(
+                                            "Property '{}' does not exist on blueprint '{}'",
+                                            name, class_name
+                                        ),
+                                        name,
+                                        self.member_names(scope, false).iter().map(String::as_str),
+                                    );
+                                    self.report(GError::from_code(
+                                        &UNDEFINED_PROPERTY,
+                                        message.as_str(),
+                                        property.einfo.clone(),
+                                    ));
+                                    Ok(ASTNode::new_noop())
                                 }
                             }
-                            AST::FUNC_CALL { .. } => {
-                                let oscope = self.current_scope.clone();
-                                self.current_scope = scope.clone();
-                                //added alternate scope arg to visit_function_call
-                                //since we want globally defined blueprints to be visible within methods of other blueprints
-                                //  println!("{:#?}", self.current_scope);
-                                let res = self.visit_function_call(property, Some(scope.clone()));
-                                self.current_scope = oscope;
-                                res
-                            }
+                            AST::FUNC_CALL { .. } => self.call_method(&obj, property),
                             AST::INDEX { .. } => {
+                                let property = self.visit_indices(property)?;
                                 let oscope = self.current_scope.clone();
                                 self.current_scope = scope.clone();
-                                let res = self.visit_index(property);
+                                let res = self.visit_index(&property);
                                 self.current_scope = oscope;
                                 res
                             }
                             _ => {
                                 println!("you are indexing something other than a method or property or list!");
-                                ASTNode::new_noop()
+                                Ok(ASTNode::new_noop())
                             }
                         }
                     }
@@ -1178,10 +1222,10 @@ impl Visitor {
                     //     AST::FUNC_CALL{name, args} => {
                     //         match name.as_str() {
                     //             "_length" => {
-                    //                 return std_string_func_length(self, &obj);
+                    //                 return std_string_func_length(self, &obj)?;
                     //             }
                     //             "_char" => {
-                    //                 return std_string_func_char(self, &obj, &args[0]);
+                    //                 return std_string_func_char(self, &obj, &args[0])?;
                     //             }
                     //             _ => {}
                     //         }
@@ -1202,7 +1246,7 @@ impl Visitor {
                             },
                             _ => {}
                         }
-                        let complex_string = self.wrap_string(&obj);
+                        let complex_string = self.wrap_string(&obj)?;
                         let redispatch = ASTNode::new(
                             AST::OBJECT_INDEX {
                                 object: Box::new(complex_string),
@@ -1214,30 +1258,298 @@ impl Visitor {
                     }
                     _ => {
                         //indexed identifier is not an object error
-                        self.errorstack
-                            .borrow_mut()
-                            .errors
-                            .push(GError::new_from_tok(
-                                ETypes::SyntaxError,
-                                "Invalid use of dot operator on non-object",
-                                node.einfo.clone(),
-                            ));
-                        self.errorstack.borrow().terminate_gs();
-                        ASTNode::new_noop()
+                        Err(GError::from_code(
+                            &NOT_AN_OBJECT,
+                            "Invalid use of dot operator on non-object",
+                            node.einfo.clone(),
+                        ))
                     }
                 }
             }
 
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
+        }
+    }
+    //evaluates the object on the left of a dot without copying it, so that methods
+    //called on it and properties assigned through it affect the original instance
+    fn visit_receiver(&mut self, object: &ASTNode) -> Result<ASTNode, GError> {
+        if let AST::VAR { name } = &object.kind {
+            let vdef = self.current_scope.borrow().resolve_var(name.clone());
+            if let Some(vdef) = vdef {
+                if let AST::VAR_DEF { value, .. } = &vdef.borrow().kind {
+                    return Ok(*value.clone());
+                }
+            }
+        }
+        self.visit(object)
+    }
+    //the object that 'self' refers to in the method currently being run, if any
+    fn resolve_self(&self) -> Option<ASTNode> {
+        let vdef = self
+            .current_scope
+            .borrow()
+            .resolve_var("self".to_string())?;
+        let vdef_b = vdef.borrow();
+        match &vdef_b.kind {
+            AST::VAR_DEF { value, .. } => Some(*value.clone()),
+            _ => None,
+        }
+    }
+    //calls a method of 'obj' with 'self' bound to it
+    //the arguments are evaluated in the caller's scope, not the object's
+    fn call_method(&mut self, obj: &ASTNode, call: &ASTNode) -> Result<ASTNode, GError> {
+        if let (AST::OBJECT { class_name, scope }, AST::FUNC_CALL { name, args }) =
+            (&obj.kind, &call.kind)
+        {
+            //functions re-exported by a module run in their home module
+            let fdef_option = Scope::resolve_func_owner(scope.clone(), name.clone());
+            if let Some((fdef, owner)) = fdef_option {
+                if let AST::FUNC_DEF {
+                    args: fdef_args, ..
+                } = &fdef.kind
+                {
+                    if args.len() != fdef_args.len() {
+                        return Err(GError::from_code(
+                            &WRONG_ARGUMENT_COUNT,
+                            format!(
+                                "Method '{}' of blueprint '{}' requires {} argument(s), not {}",
+                                name,
+                                class_name,
+                                fdef_args.len(),
+                                args.len()
+                            )
+                            .as_str(),
+                            call.einfo.clone(),
+                        ));
+                    }
+                }
+                let arg_vals = args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<_, _>>()?;
+                return self.invoke_function(
+                    &fdef,
+                    arg_vals,
+                    owner,
+                    Some(obj.clone()),
+                    &call.einfo,
+                );
+            }
+            let message = with_suggestion(
+                format!(
+                    "Method '{}' does not exist on blueprint '{}'",
+                    name, class_name
+                ),
+                name,
+                self.member_names(scope, true).iter().map(String::as_str),
+            );
+            self.report(GError::from_code(
+                &UNDEFINED_METHOD,
+                message.as_str(),
+                call.einfo.clone(),
+            ));
+        }
+        Ok(ASTNode::new_noop())
+    }
+    //evaluates the indices of a list property like 'obj.list[i]' in the caller's scope,
+    //before the list itself is looked up inside the object's scope
+    fn visit_indices(&mut self, property: &ASTNode) -> Result<ASTNode, GError> {
+        match &property.kind {
+            AST::INDEX { target, indices } => Ok(ASTNode::new(
+                AST::INDEX {
+                    target: target.clone(),
+                    indices: indices
+                        .iter()
+                        .map(|i| self.visit(i))
+                        .collect::<Result<_, _>>()?,
+                },
+                property.einfo.clone(),
+            )),
+            _ => Ok(property.clone()),
+        }
+    }
+    //a blueprint name on the left of a dot refers to the static members of that blueprint,
+    //as long as there is no variable with the same name
+    //it can be qualified by the modules it is in, like 'mod.Thing.build()', the same way as in 'new mod.Thing()'
+    fn resolve_static_object(&self, object: &ASTNode) -> Option<ASTNode> {
+        let names = dotted_names(object)?;
+        let (name, module_path) = names.split_last()?;
+        let blueprint = if module_path.is_empty() {
+            let cs = self.current_scope.borrow();
+            if cs.resolve_var(name.clone()).is_some() {
+                return None;
+            }
+            cs.resolve_blueprint(name.clone())
+        } else {
+            //anything that isn't a module, like 'obj.prop.method()', is left to the usual lookup
+            let module_scope = self.resolve_module(module_path, &object.einfo).ok()?;
+            let ms = module_scope.borrow();
+            if ms.variables.contains_key(name) || ms.private.contains(name) {
+                return None;
+            }
+            ms.classes.get(name).cloned()
+        };
+        if let Some(ASTNode {
+            kind:
+                AST::CLASS {
+                    statics: Some(statics),
+                    ..
+                },
+            ..
+        }) = blueprint
+        {
+            return Some(ASTNode::new(
+                AST::OBJECT {
+                    class_name: name.clone(),
+                    scope: statics,
+                },
+                object.einfo.clone(),
+            ));
+        }
+        None
+    }
+    //gets a mutable reference to the definition of the identifier on the left of a dot,
+    //which is either a variable or a blueprint with static members
+    fn resolve_dot_target(&mut self, object: &ASTNode, name: &str) -> Rc<RefCell<ASTNode>> {
+        if let Some(vdef) = self.current_scope.borrow().resolve_var(name.to_string()) {
+            return vdef;
         }
+        if let Some(statics) = self.resolve_static_object(object) {
+            return Rc::new(RefCell::new(ASTNode::new(
+                AST::VAR_DEF {
+                    name: name.to_string(),
+                    value: Box::new(statics),
+                },
+                object.einfo.clone(),
+            )));
+        }
+        //blueprints can be on the left of a dot too, for their static members
+        let mut names = self.current_scope.borrow().visible_vars();
+        names.extend(self.current_scope.borrow().visible_blueprints());
+        let message = with_suggestion(
+            format!("Variable '{}' does not exist in the current scope", name),
+            name,
+            names.iter().map(String::as_str),
+        );
+        self.report(GError::from_code(
+            &UNDEFINED_VARIABLE,
+            message.as_str(),
+            object.einfo.clone(),
+        ));
+        Rc::new(RefCell::new(ASTNode::new_noop()))
+    }
+    //returns whether 'scope' is the current scope or one of its ancestors,
+    //which is the case while running one of that object's own methods
+    fn within_scope(&self, scope: &Rc<RefCell<Scope>>) -> bool {
+        let mut cs = Some(self.current_scope.clone());
+        while let Some(s) = cs {
+            if Rc::ptr_eq(&s, scope) {
+                return true;
+            }
+            cs = s.borrow().parent.as_ref().and_then(|p| p.upgrade());
+        }
+        false
+    }
+    //whether private members of the object with this scope can be used from here,
+    //which is from any method of its blueprint, also when it is run on another instance
+    fn can_use_private(&self, scope: &Rc<RefCell<Scope>>) -> bool {
+        if self.within_scope(scope) {
+            return true;
+        }
+        let s = scope.borrow();
+        let statics = s.parent.as_ref().and_then(|p| p.upgrade());
+        match statics {
+            Some(statics) if s.instance => self.within_scope(&statics),
+            _ => false,
+        }
+    }
+    //properties (or methods) of an object that can be used from here, for suggesting names
+    fn member_names(&self, scope: &Rc<RefCell<Scope>>, methods: bool) -> Vec<String> {
+        let s = scope.borrow();
+        let names: Vec<&String> = if methods {
+            s.functions.keys().collect()
+        } else {
+            s.variables.keys().chain(s.getters.keys()).collect()
+        };
+        let within = self.can_use_private(scope);
+        names
+            .into_iter()
+            .filter(|n| within || !s.private.contains(*n))
+            .cloned()
+            .collect()
+    }
+    //whether the getter or setter for 'name' is being run on the object with this scope
+    fn running_accessor(&self, scope: &Rc<RefCell<Scope>>, name: &str) -> bool {
+        self.accessors
+            .iter()
+            .any(|(s, n)| Rc::ptr_eq(s, scope) && n == name)
+    }
+    //the getter (or setter) for a property, unless it is already being run on the same object
+    fn accessor(&self, scope: &Rc<RefCell<Scope>>, name: &str, setter: bool) -> Option<ASTNode> {
+        if self.running_accessor(scope, name) {
+            return None;
+        }
+        let s = scope.borrow();
+        let accessors = if setter { &s.setters } else { &s.getters };
+        accessors.get(name).cloned()
     }
-    fn obj_index_mut(&mut self, node: &ASTNode) -> Rc<RefCell<ASTNode>> {
+    fn invoke_accessor(
+        &mut self,
+        accessor: &ASTNode,
+        arg_vals: Vec<ASTNode>,
+        scope: &Rc<RefCell<Scope>>,
+        self_obj: ASTNode,
+        name: &str,
+        call_site: &ErrorInfo,
+    ) -> Result<ASTNode, GError> {
+        self.accessors.push((scope.clone(), name.to_string()));
+        let res =
+            self.invoke_function(accessor, arg_vals, scope.clone(), Some(self_obj), call_site);
+        self.accessors.pop();
+        res
+    }
+    //private members can only be accessed from within the object's own methods, anything else is a BlueprintError that stops the script
+    fn check_private_access(
+        &mut self,
+        class_name: &str,
+        scope: &Rc<RefCell<Scope>>,
+        property: &ASTNode,
+    ) -> Result<(), GError> {
+        let name = match &property.kind {
+            AST::VAR { name } | AST::FUNC_CALL { name, .. } => name,
+            AST::INDEX { target, .. } => match &target.kind {
+                AST::VAR { name } => name,
+                _ => return Ok(()),
+            },
+            _ => return Ok(()),
+        };
+        if !scope.borrow().private.contains(name) || self.can_use_private(scope) {
+            return Ok(());
+        }
+        let (code, message) = if class_name == "import object" {
+            (
+                &NOT_EXPORTED,
+                format!("'{}' is not exported by the imported module", name),
+            )
+        } else {
+            (
+                &PRIVATE_MEMBER,
+                format!("Member '{}' of blueprint '{}' is private", name, class_name),
+            )
+        };
+        Err(GError::from_code(
+            code,
+            message.as_str(),
+            property.einfo.clone(),
+        ))
+    }
+    fn obj_index_mut(&mut self, node: &ASTNode) -> Result<Rc<RefCell<ASTNode>>, GError> {
         match &node.kind {
             AST::OBJECT_INDEX { object, property } => {
                 //println!("object side: {:#?}", object);
                 //println!("property side: {:#?}", property);
                 let obj = match &object.kind {
-                    AST::OBJECT_INDEX { .. } => self.obj_index_mut(object),
+                    AST::OBJECT_INDEX { .. } => self.obj_index_mut(object)?,
                     AST::OBJECT {
                         class_name: _,
                         scope,
@@ -1248,17 +1560,10 @@ impl Visitor {
                         }
                         _ => {
                             println!("something");
-                            return Rc::new(RefCell::new(ASTNode::new_noop()));
+                            return Ok(Rc::new(RefCell::new(ASTNode::new_noop())));
                         }
                     },
-                    AST::VAR { name } => {
-                        let odef = self
-                            .current_scope
-                            .borrow()
-                            .resolve_var(name.clone())
-                            .unwrap();
-                        odef
-                    }
+                    AST::VAR { name } => self.resolve_dot_target(object, name),
                     _ => {
                         println!("NOOO!");
                         Rc::new(RefCell::new(ASTNode::new_noop()))
@@ -1269,59 +1574,83 @@ impl Visitor {
                     AST::VAR_DEF { name: _, value } => {
                         //println!("prop name: {}", n);
                         match &value.kind {
-                            AST::OBJECT {
-                                class_name: _,
-                                scope,
-                            } => {
+                            AST::OBJECT { class_name, scope } => {
+                                self.check_private_access(class_name, scope, property)?;
                                 match &property.kind {
                                     AST::VAR { name } => {
                                         //println!("prop name: {}", name);
+                                        if let Some(getter) = self.accessor(scope, name, false) {
+                                            let got = self.invoke_accessor(
+                                                &getter,
+                                                vec![],
+                                                scope,
+                                                *value.clone(),
+                                                name,
+                                                &property.einfo,
+                                            )?;
+                                            return Ok(Rc::new(RefCell::new(ASTNode::new(
+                                                AST::VAR_DEF {
+                                                    name: name.clone(),
+                                                    value: Box::new(got),
+                                                },
+                                                property.einfo.clone(),
+                                            ))));
+                                        }
                                         if let Some(val) = scope.borrow().resolve_var(name.clone())
                                         {
                                             //println!("val of p prop: {:#?}", val.clone());
-                                            Rc::clone(&val)
+                                            Ok(Rc::clone(&val))
                                         } else {
-                                            println!("property does not exist in object");
-                                            std::process::exit(1)
+                                            let message = with_suggestion(
+                                                format!(
+                                                    "Property '{}' does not exist on blueprint '{}'",
+                                                    name, class_name
+                                                ),
+                                                name,
+                                                self.member_names(scope, false)
+                                                    .iter()
+                                                    .map(String::as_str),
+                                            );
+                                            Err(GError::from_code(
+                                                &UNDEFINED_PROPERTY,
+                                                message.as_str(),
+                                                property.einfo.clone(),
+                                            ))
                                         }
                                     }
                                     AST::INDEX { .. } => {
+                                        let property = self.visit_indices(property)?;
                                         let oscope = self.current_scope.clone();
                                         self.current_scope = scope.clone();
                                         let mut_list_ref = self.list_get_mut(&property);
                                         self.current_scope = oscope;
-                                        Rc::clone(&mut_list_ref)
+                                        mut_list_ref
                                     }
-                                    _ => Rc::new(RefCell::new(ASTNode::new_noop())),
+                                    _ => Ok(Rc::new(RefCell::new(ASTNode::new_noop()))),
                                 }
                             }
-                            _ => {
-                                self.errorstack
-                                    .borrow_mut()
-                                    .errors
-                                    .push(GError::new_from_tok(
-                                        ETypes::SyntaxError,
-                                        "Invalid use of dot operator on non-object",
-                                        property.einfo.clone(),
-                                    ));
-                                self.errorstack.borrow().terminate_gs();
-                                Rc::new(RefCell::new(ASTNode::new_noop()))
-                            }
+                            _ => Err(GError::from_code(
+                                &NOT_AN_OBJECT,
+                                "Invalid use of dot operator on non-object",
+                                property.einfo.clone(),
+                            )),
                         }
                     }
-                    _ => {
-                        println!("here");
-                        std::process::exit(1)
-                    }
+                    _ => Err(GError::from_code(
+                        &NOT_AN_OBJECT,
+                        "Invalid use of dot operator on non-object",
+                        object.einfo.clone(),
+                    )),
                 }
             }
-            _ => {
-                println!("here1");
-                std::process::exit(1)
-            }
+            _ => Err(GError::from_code(
+                &NOT_AN_OBJECT,
+                "Expected an object index",
+                node.einfo.clone(),
+            )),
         }
     }
-    pub fn visit_obj_reassign(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_obj_reassign(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::OBJECT_REASSIGN {
                 object_index,
@@ -1331,7 +1660,15 @@ impl Visitor {
                     AST::OBJECT_INDEX { object, property } => {
                         let mut ei = object.einfo.clone();
 
+                        let statics = match &object.kind {
+                            AST::OBJECT_INDEX { .. } => self.resolve_static_object(object),
+                            _ => None,
+                        };
                         let obj = match &object.kind {
+                            //the static members of a blueprint in a module, as in mod.Thing.x
+                            AST::OBJECT_INDEX { .. } if statics.is_some() => {
+                                Rc::new(RefCell::new(statics.unwrap()))
+                            }
                             //this is in the case that object is another OBJECT_INDEX
                             //as in var.x.y
                             AST::OBJECT_INDEX {
@@ -1339,19 +1676,69 @@ impl Visitor {
                                 property: pr,
                             } => {
                                 ei = pr.einfo.clone();
-                                self.obj_index_mut(object)
+                                self.obj_index_mut(object)?
                             }
                             //and this is the case where object would literally just be the identifier for the object
                             //like the 'var' in var.x
-                            AST::VAR { name } => self
-                                .current_scope
-                                .borrow()
-                                .resolve_var(name.clone())
-                                .unwrap(),
+                            AST::VAR { name } => self.resolve_dot_target(object, name),
+                            //and this is the case of an object stored in a list, like the 'var[0]' in var[0].x
+                            //the element shares its scope with the one stored in the list
+                            AST::INDEX { .. } => Rc::new(RefCell::new(self.visit(object)?)),
                             _ => Rc::new(RefCell::new(ASTNode::new_noop())),
                         };
 
-                        let new_value = self.visit(&value);
+                        let new_value = self.visit(&value)?;
+                        //indices of 'obj.list[i] = value' are evaluated here, before the object is borrowed
+                        let property = &self.visit_indices(property)?;
+                        //assigning to a property with a setter calls the setter instead
+                        if let AST::VAR { name } = &property.kind {
+                            let target = match &obj.borrow().kind {
+                                AST::VAR_DEF { value: v, .. } => match &v.kind {
+                                    AST::OBJECT { class_name, scope } => {
+                                        Some((class_name.clone(), scope.clone()))
+                                    }
+                                    _ => None,
+                                },
+                                AST::OBJECT { class_name, scope } => {
+                                    Some((class_name.clone(), scope.clone()))
+                                }
+                                _ => None,
+                            };
+                            if let Some((class_name, scope)) = target {
+                                self.check_private_access(&class_name, &scope, property)?;
+                                if let Some(setter) = self.accessor(&scope, name, true) {
+                                    let self_obj = ASTNode::new(
+                                        AST::OBJECT {
+                                            class_name: class_name.clone(),
+                                            scope: scope.clone(),
+                                        },
+                                        object.einfo.clone(),
+                                    );
+                                    self.invoke_accessor(
+                                        &setter,
+                                        vec![new_value],
+                                        &scope,
+                                        self_obj,
+                                        name,
+                                        &node.einfo,
+                                    )?;
+                                    return Ok(ASTNode::new_noop());
+                                }
+                                if scope.borrow().getters.contains_key(name)
+                                    && !self.running_accessor(&scope, name)
+                                {
+                                    return Err(GError::from_code(
+                                            &GETTER_WITHOUT_SETTER,
+                                            format!(
+                                                "Property '{}' of blueprint '{}' has a getter but no setter",
+                                                name, class_name
+                                            )
+                                            .as_str(),
+                                            property.einfo.clone(),
+                                        ));
+                                }
+                            }
+                        }
                         let mut obj_b = obj.borrow_mut();
                         let new_value_einfo = new_value.einfo.clone();
                         let new_value_vardef = match &property.kind {
@@ -1366,11 +1753,8 @@ impl Visitor {
                         };
                         //obj_b is a var def in the case of a SIMPLE property, like var.x
                         if let AST::VAR_DEF { name: _, value: v } = &mut obj_b.kind {
-                            if let AST::OBJECT {
-                                class_name: _,
-                                scope,
-                            } = &v.kind
-                            {
+                            if let AST::OBJECT { class_name, scope } = &v.kind {
+                                self.check_private_access(class_name, scope, property)?;
                                 match &property.kind {
                                     AST::VAR { name } => {
                                         let _ = scope
@@ -1380,77 +1764,55 @@ impl Visitor {
                                     AST::INDEX { .. } => {
                                         let oscope = self.current_scope.clone();
                                         self.current_scope = scope.clone();
-                                        let list_reassign = ASTNode::new(
-                                            AST::LIST_REASSIGN {
-                                                target: Box::new(*property.clone()),
-                                                value: Box::new(new_value.clone()),
-                                            },
-                                            new_value_einfo.clone(),
-                                        );
-                                        self.visit_list_reassign(&list_reassign);
+                                        let res =
+                                            self.assign_list_element(property, new_value.clone());
                                         self.current_scope = oscope;
+                                        res?;
                                     }
-                                    _ => return ASTNode::new_noop(),
+                                    _ => return Ok(ASTNode::new_noop()),
                                 }
                             } else {
-                                self.errorstack
-                                    .borrow_mut()
-                                    .errors
-                                    .push(GError::new_from_tok(
-                                        ETypes::SyntaxError,
-                                        "Invalid use of dot operator on non-object",
-                                        ei.clone(),
-                                    ));
-                                self.errorstack.borrow().terminate_gs();
+                                return Err(GError::from_code(
+                                    &NOT_AN_OBJECT,
+                                    "Invalid use of dot operator on non-object",
+                                    ei.clone(),
+                                ));
                             }
                         //HOWEVER, obj_b will not be a VAR_DEF because of the stupid way I implemented this...
                         // it will instead be AST::OBJECT as a result of calling list_get_mut
                         // basically this is the exact same code as above
                         // this bottom code will run when the gsc code looks like 'var.x[1].prop = 5'
-                        } else if let AST::OBJECT {
-                            class_name: _,
-                            scope,
-                        } = &mut obj_b.kind
-                        {
+                        } else if let AST::OBJECT { class_name, scope } = &mut obj_b.kind {
+                            self.check_private_access(class_name, scope, property)?;
                             match &property.kind {
                                 AST::VAR { name } => {
                                     let _ =
                                         scope.borrow_mut().set_var(name.clone(), &new_value_vardef);
                                 }
                                 //This is if the gsc code looks like 'var.x.prop[1] = 5'
-                                // it simply artificially creates a LIST_REASSIGN node and goes within the object's scope
+                                // it simply goes within the object's scope and assigns the element there
                                 AST::INDEX { .. } => {
                                     let oscope = self.current_scope.clone();
                                     self.current_scope = scope.clone();
-                                    let list_reassign = ASTNode::new(
-                                        AST::LIST_REASSIGN {
-                                            target: Box::new(*property.clone()),
-                                            value: Box::new(new_value.clone()),
-                                        },
-                                        new_value_einfo.clone(),
-                                    );
-                                    self.visit_list_reassign(&list_reassign);
+                                    let res = self.assign_list_element(property, new_value.clone());
                                     self.current_scope = oscope;
+                                    res?;
                                 }
-                                _ => return ASTNode::new_noop(),
+                                _ => return Ok(ASTNode::new_noop()),
                             }
                         } else {
-                            self.errorstack
-                                .borrow_mut()
-                                .errors
-                                .push(GError::new_from_tok(
-                                    ETypes::SyntaxError,
-                                    "Token indexed with dot is not an object",
-                                    ei.clone(),
-                                ));
-                            self.errorstack.borrow().terminate_gs();
+                            return Err(GError::from_code(
+                                &NOT_AN_OBJECT,
+                                "Token indexed with dot is not an object",
+                                ei.clone(),
+                            ));
                         }
-                        return ASTNode::new_noop();
+                        return Ok(ASTNode::new_noop());
                     }
-                    _ => ASTNode::new_noop(),
+                    _ => Ok(ASTNode::new_noop()),
                 }
             }
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
     //(old, for testing purposes)
@@ -1465,120 +1827,81 @@ impl Visitor {
     //--REVISED FUNCTION--
     // -- UPDATE --
     // made LIST's contents Vec<Rc<RefCell<ASTNode>>> so
-    pub fn visit_list_reassign(&mut self, node: &ASTNode) -> ASTNode {
+    // -- UPDATE 2 --
+    // lists are shared by every variable that refers to them, so the element is now
+    // overwritten in place instead of writing a modified copy of the list back to the variable
+    pub fn visit_list_reassign(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::LIST_REASSIGN { target, value } => {
-                let value = self.visit(value);
-                match &target.kind {
-                    AST::INDEX {
-                        target: list,
-                        indices,
-                    } => {
-                        let list_name = match &list.kind {
-                            AST::VAR { name } => name.clone(),
-                            _ => String::new(),
-                        };
-                        // the way this works is we gain a copy of the list referred to by 'list'
-                        // then we store it in orig_list
-                        // then iterate through the indices, using list_ref_i as a mutable reference to the contents
-                        // of orig_list to finally change the desired value
-                        // then we set orig_list to the actual list stored in the scope
-                        let orig_list = self.visit(list);
-                        let orig_list_ref = Rc::new(RefCell::new(orig_list));
-                        let mut list_ref_i = orig_list_ref.clone();
-
-                        //at this point list_ref_i is a mutable reference to the list
-                        //referred to by the identifier value of "list"
-                        for (i, i_node) in indices.iter().enumerate() {
-                            let i_val = self.visit(i_node);
-                            let actual_i = match i_val.kind {
-                                AST::INT { int_value } => int_value,
-                                _ => {
-                                    self.errorstack
-                                        .borrow_mut()
-                                        .errors
-                                        .push(GError::new_from_tok(
-                                            ETypes::ListError,
-                                            "Expected integer to index list",
-                                            i_val.einfo.clone(),
-                                        ));
-                                    self.errorstack.borrow().terminate_gs();
-                                    0
-                                }
-                            };
-                            //println!("{}", actual_i);
-                            let e = list_ref_i.borrow().einfo.clone();
-                            if i == indices.len() - 1 {
-                                //now we know we have reached the point to actually set the variable
-
-                                match &mut list_ref_i.borrow_mut().kind {
-                                    AST::LIST { contents } => {
-                                        //println!("here");
-                                        //make sure index is valid
-                                        if actual_i < 0 || actual_i as usize >= contents.len() {
-                                            self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::ListError, format!("Index {} is out of bounds for list of length {}", actual_i, contents.len()).as_str(), i_val.einfo.clone()));
-                                            self.errorstack.borrow().terminate_gs();
-                                        }
-                                        contents[actual_i as usize] =
-                                            Rc::new(RefCell::new(value.clone()));
-                                    }
-                                    _ => {
-                                        self.errorstack.borrow_mut().errors.push(
-                                            GError::new_from_tok(
-                                                ETypes::ListError,
-                                                "Indexed object is not a list",
-                                                e.clone(),
-                                            ),
-                                        );
-                                        return ASTNode::new_noop();
-                                    } //handle error here
-                                }
-                            } else {
-                                let thingy = list_ref_i.clone();
-                                let mut thingy2 = thingy.borrow_mut();
-                                match &mut thingy2.kind {
-                                    AST::LIST { contents } => {
-                                        //make sure index is valid
-                                        if actual_i < 0 || actual_i as usize >= contents.len() {
-                                            self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::ListError, format!("Index {} is out of bounds for list of length {}", actual_i, contents.len()).as_str(), i_val.einfo.clone()));
-                                            self.errorstack.borrow().terminate_gs();
-                                        }
-                                        list_ref_i = contents[actual_i as usize].clone();
-                                    }
-                                    _ => {
-                                        self.errorstack.borrow_mut().errors.push(
-                                            GError::new_from_tok(
-                                                ETypes::ListError,
-                                                "Indexed object is not a list",
-                                                e.clone(),
-                                            ),
-                                        );
-                                        return ASTNode::new_noop();
-                                    } //handle error here
-                                }
+                let value = self.visit(value)?;
+                self.assign_list_element(target, value)
+            }
+            _ => Ok(ASTNode::new_noop()),
+        }
+    }
+    // sets the element of an n-dimensional list that 'target' (an AST::INDEX) refers to
+    // value must already be evaluated
+    pub fn assign_list_element(
+        &mut self,
+        target: &ASTNode,
+        value: ASTNode,
+    ) -> Result<ASTNode, GError> {
+        match &target.kind {
+            AST::INDEX {
+                target: list,
+                indices,
+            } => {
+                // visiting the list gives a node that shares its elements with the stored list
+                let list = self.visit(list)?;
+                let mut element = Rc::new(RefCell::new(list));
+                for i_node in indices {
+                    let i_val = self.visit(i_node)?;
+                    let actual_i = match i_val.kind {
+                        AST::INT { int_value } => int_value,
+                        _ => {
+                            return Err(GError::from_code(
+                                &INDEX_NOT_INTEGER,
+                                "Expected integer to index list",
+                                i_val.einfo.clone(),
+                            ));
+                        }
+                    };
+                    let e = element.borrow().einfo.clone();
+                    let next = match &element.borrow().kind {
+                        AST::LIST { contents } => {
+                            //make sure index is valid
+                            if actual_i < 0 || actual_i as usize >= contents.len() {
+                                return Err(GError::from_code(
+                                    &INDEX_OUT_OF_BOUNDS,
+                                    format!(
+                                        "Index {} is out of bounds for list of length {}",
+                                        actual_i,
+                                        contents.len()
+                                    )
+                                    .as_str(),
+                                    i_val.einfo.clone(),
+                                ));
                             }
-                            let _ = self.current_scope.borrow_mut().set_var(
-                                list_name.clone(),
-                                &ASTNode::new(
-                                    AST::VAR_DEF {
-                                        name: list_name.clone(),
-                                        value: Box::new(orig_list_ref.borrow().clone()),
-                                    },
-                                    e.clone(),
-                                ),
-                            );
+                            contents[actual_i as usize].clone()
                         }
-                        // list_ref.print();
-                        return ASTNode::new_noop();
-                    }
-                    _ => return ASTNode::new_noop(),
+                        _ => {
+                            return Err(GError::from_code(
+                                &NOT_A_LIST,
+                                "Indexed object is not a list",
+                                e,
+                            ));
+                        }
+                    };
+                    element = next;
                 }
+                *element.borrow_mut() = value;
+                Ok(ASTNode::new_noop())
             }
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
     // this gets a mutable reference (in the form of Rc<RefCell<ASTNode>>) to an element of an n-dimensional list
-    pub fn list_get_mut(&mut self, node: &ASTNode) -> Rc<RefCell<ASTNode>> {
+    pub fn list_get_mut(&mut self, node: &ASTNode) -> Result<Rc<RefCell<ASTNode>>, GError> {
         match &node.kind {
             AST::INDEX { target, indices } => {
                 let list_name = match &target.kind {
@@ -1598,19 +1921,15 @@ impl Visitor {
                         } => {
                             let mut curr_ref = Rc::new(RefCell::new(ASTNode::new_noop()));
                             for (i, i_node) in indices.iter().enumerate() {
-                                let i_val = self.visit(i_node);
+                                let i_val = self.visit(i_node)?;
                                 let actual_i = match i_val.kind {
                                     AST::INT { int_value } => int_value,
                                     _ => {
-                                        self.errorstack.borrow_mut().errors.push(
-                                            GError::new_from_tok(
-                                                ETypes::ListError,
-                                                "Expected integer to index list",
-                                                i_val.einfo.clone(),
-                                            ),
-                                        );
-                                        self.errorstack.borrow().terminate_gs();
-                                        0
+                                        return Err(GError::from_code(
+                                            &INDEX_NOT_INTEGER,
+                                            "Expected integer to index list",
+                                            i_val.einfo.clone(),
+                                        ));
                                     }
                                 };
                                 if i == 0 {
@@ -1620,11 +1939,11 @@ impl Visitor {
                                 if i == indices.len() - 1 {
                                     match &curr_ref.borrow().kind {
                                         AST::LIST { contents } => {
-                                            return contents[actual_i as usize].clone();
+                                            return Ok(contents[actual_i as usize].clone());
                                         }
                                         _ => {
                                             println!("err1 {:#?}", curr_ref.borrow());
-                                            return Rc::new(RefCell::new(ASTNode::new_noop()));
+                                            return Ok(Rc::new(RefCell::new(ASTNode::new_noop())));
                                         }
                                     }
                                 } else {
@@ -1634,50 +1953,59 @@ impl Visitor {
                                         AST::LIST { contents } => {
                                             curr_ref = contents[actual_i as usize].clone()
                                         }
-                                        _ => return Rc::new(RefCell::new(ASTNode::new_noop())),
+                                        _ => return Ok(Rc::new(RefCell::new(ASTNode::new_noop()))),
                                     }
                                 }
                             }
 
-                            curr_ref
+                            Ok(curr_ref)
                         }
-                        _ => Rc::new(RefCell::new(ASTNode::new_noop())),
+                        _ => Ok(Rc::new(RefCell::new(ASTNode::new_noop()))),
                     },
-                    _ => Rc::new(RefCell::new(ASTNode::new_noop())),
+                    _ => Ok(Rc::new(RefCell::new(ASTNode::new_noop()))),
                 }
             }
-            _ => Rc::new(RefCell::new(ASTNode::new_noop())),
+            _ => Ok(Rc::new(RefCell::new(ASTNode::new_noop()))),
         }
     }
 
-    pub fn visit_blueprint(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_blueprint(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
-            AST::CLASS { name, .. } => {
+            AST::CLASS {
+                name,
+                static_properties,
+                static_methods,
+                private,
+                ..
+            } => {
                 if self.keywords.contains(name) {
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::BlueprintError,
-                            "Illegal use of keyword for blueprint definition",
-                            node.einfo.clone(),
-                        ));
-                    return ASTNode::new_noop();
+                    return Err(GError::from_code(
+                        &KEYWORD_AS_BLUEPRINT,
+                        "Illegal use of keyword for blueprint definition",
+                        node.einfo.clone(),
+                    ));
                 }
-                if let Err(s) = self.current_scope.borrow_mut().add_blueprint(node) {
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::BlueprintError,
-                            s.as_str(),
-                            node.einfo.clone(),
-                        ));
-                    self.errorstack.borrow().terminate_gs();
+                //static members live in their own scope, which is shared by every instance
+                let statics = Rc::new(RefCell::new(Scope::new(Some(self.current_scope.clone()))));
+                self.add_properties(&statics, static_properties)?;
+                for method in static_methods.values() {
+                    let _ = statics.borrow_mut().add_func(method);
                 }
-                node.clone()
+                statics.borrow_mut().private = private.clone();
+                let mut blueprint = node.clone();
+                if let AST::CLASS { statics: s, .. } = &mut blueprint.kind {
+                    *s = Some(statics);
+                }
+                if let Err(s) = self.current_scope.borrow_mut().add_blueprint(&blueprint) {
+                    return Err(GError::from_code(
+                        &BLUEPRINT_EXISTS,
+                        s.as_str(),
+                        node.einfo.clone(),
+                    ));
+                }
+                Ok(blueprint)
             }
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
     //--ISSUE--
@@ -1685,158 +2013,248 @@ impl Visitor {
     //--SOLUTION--
     //but to fix this I will need to make parent scope Rc RefCell
     //fixed so far
-    pub fn visit_new(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn visit_new(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         let original_scope = self.current_scope.clone();
         match &node.kind {
             AST::NEW {
                 name,
+                module_path,
                 args: new_args,
             } => {
-                let b_option = original_scope.borrow().resolve_blueprint(name.clone());
+                let b_option = if module_path.is_empty() {
+                    original_scope.borrow().resolve_blueprint(name.clone())
+                } else {
+                    let module_scope = self.resolve_module(module_path, &node.einfo)?;
+                    let blueprint = module_scope
+                        .borrow()
+                        .classes
+                        .get(name)
+                        .filter(|_| !module_scope.borrow().private.contains(name))
+                        .cloned();
+                    if blueprint.is_none() {
+                        let ms = module_scope.borrow();
+                        let message = with_suggestion(
+                            format!(
+                                "Blueprint '{}' does not exist in module '{}'",
+                                name,
+                                module_path.join(".")
+                            ),
+                            name,
+                            ms.classes
+                                .keys()
+                                .filter(|c| !ms.private.contains(*c))
+                                .map(String::as_str),
+                        );
+                        return Err(GError::from_code(
+                            &UNDEFINED_BLUEPRINT,
+                            message.as_str(),
+                            node.einfo.clone(),
+                        ));
+                    }
+                    blueprint
+                };
                 if let Some(blueprint) = b_option {
                     let class_e = blueprint.einfo.clone();
                     match &blueprint.kind {
                         AST::CLASS {
-                            name: _,
-                            properties,
-                            methods,
+                            methods, statics, ..
                         } => {
-                            let obj_scope =
-                                Rc::new(RefCell::new(Scope::new(Some(original_scope.clone()))));
+                            //instances see the static members of their blueprint through their parent scope
+                            let obj_parent = statics.clone().unwrap_or(original_scope.clone());
+                            let obj_scope = Rc::new(RefCell::new(Scope::new(Some(obj_parent))));
+                            obj_scope.borrow_mut().instance = statics.is_some();
                             //let obj_scope = Rc::new(RefCell::new(Scope::new(None)));
                             let root_scope = Scope::get_root_scope(self.current_scope.clone());
                             //adding properties
-                            for (_name, prop) in properties {
-                                let _ = obj_scope.borrow_mut().add_var(prop);
-                            }
+                            self.init_object_scope(&obj_scope, &blueprint)?;
                             for (_name, bp) in &root_scope.borrow().classes {
                                 let _ = obj_scope.borrow_mut().add_blueprint(bp);
                             }
                             // println!("methods len while visiting new: {}", methods.len());
                             if let Some(constructor) = methods.get("create") {
-                                self.current_scope = obj_scope.clone();
-                                let fdef_args =
-                                    if let AST::FUNC_DEF { args: fa, .. } = &constructor.kind {
-                                        fa
-                                    } else {
-                                        &vec![]
-                                    };
-                                for (argdef, arg) in fdef_args.iter().zip(new_args.iter()) {
-                                    let arg_val = self.visit(arg);
-                                    if let AST::VAR_DEF {
-                                        name: argdef_name, ..
-                                    } = &argdef.kind
-                                    {
-                                        let _ = obj_scope.borrow_mut().add_var(&ASTNode::new(
-                                            AST::VAR_DEF {
-                                                name: argdef_name.clone(),
-                                                value: Box::new(arg_val),
-                                            },
-                                            arg.einfo.clone(),
-                                        ));
+                                //adding methods before the constructor runs, so that it can call them
+                                //methods shadow any functions with the same name outside the object
+                                for (method_name, method) in methods {
+                                    if method_name != "create" {
+                                        obj_scope
+                                            .borrow_mut()
+                                            .functions
+                                            .insert(method_name.clone(), method.clone());
                                     }
                                 }
-                                if let AST::FUNC_DEF { body: fbody, .. } = &constructor.kind {
-                                    self.visit(fbody);
-                                }
-                                self.current_scope = original_scope.clone();
-                                //adding methods
-                                let mut new_methods = methods.clone();
-                                new_methods.remove("create");
-                                for (_name, method) in new_methods {
-                                    let _ = obj_scope.borrow_mut().add_func(&method);
-                                }
-                                ASTNode::new(
+                                let obj = ASTNode::new(
                                     AST::OBJECT {
                                         class_name: name.clone(),
-                                        scope: obj_scope,
+                                        scope: obj_scope.clone(),
                                     },
                                     node.einfo.clone(),
-                                )
+                                );
+                                //arguments are evaluated where 'new' is called, not inside the new object
+                                let arg_vals: Vec<ASTNode> = new_args
+                                    .iter()
+                                    .map(|arg| self.visit(arg))
+                                    .collect::<Result<_, _>>()?;
+                                self.invoke_function(
+                                    constructor,
+                                    arg_vals,
+                                    obj_scope,
+                                    Some(obj.clone()),
+                                    &node.einfo,
+                                )?;
+                                Ok(obj)
                             } else {
                                 //expected constructor method to exist error
-                                self.errorstack
-                                    .borrow_mut()
-                                    .errors
-                                    .push(GError::new_from_tok(
-                                    ETypes::BlueprintError,
+                                Err(GError::from_code(
+                                    &MISSING_CONSTRUCTOR,
                                     "Expected constructor method 'create' for blueprint definition",
                                     class_e.clone(),
-                                ));
-                                self.errorstack.borrow().terminate_gs();
-                                ASTNode::new_noop()
+                                ))
                             }
                         }
-                        _ => return ASTNode::new_noop(),
+                        _ => return Ok(ASTNode::new_noop()),
                     }
                 } else {
                     //undefined blueprint error
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::BlueprintError,
-                            format!(
-                                "Blueprint '{}' does not exist in the current scope",
-                                name.clone()
-                            )
-                            .as_str(),
-                            node.einfo.clone(),
-                        ));
-                    self.errorstack.borrow().terminate_gs();
-                    ASTNode::new_noop()
+                    let blueprints = original_scope.borrow().visible_blueprints();
+                    let message = with_suggestion(
+                        format!("Blueprint '{}' does not exist in the current scope", name),
+                        name,
+                        blueprints.iter().map(String::as_str),
+                    );
+                    Err(GError::from_code(
+                        &UNDEFINED_BLUEPRINT,
+                        message.as_str(),
+                        node.einfo.clone(),
+                    ))
                 }
             }
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
         }
     }
 
-    pub fn visit_import(&mut self, node: &ASTNode) -> ASTNode {
+    //adds the properties, accessors and private members declared in 'blueprint' to the scope of a new instance
+    //property defaults are evaluated here, so that every instance gets its own values
+    fn init_object_scope(
+        &mut self,
+        obj_scope: &Rc<RefCell<Scope>>,
+        blueprint: &ASTNode,
+    ) -> Result<(), GError> {
+        if let AST::CLASS {
+            properties,
+            getters,
+            setters,
+            private,
+            ..
+        } = &blueprint.kind
+        {
+            self.add_properties(obj_scope, properties)?;
+            let mut obj_scope_b = obj_scope.borrow_mut();
+            obj_scope_b.getters = getters.clone();
+            obj_scope_b.setters = setters.clone();
+            obj_scope_b.private = private.clone();
+        }
+        Ok(())
+    }
+    //adds properties to 'scope', evaluating their defaults inside it in the order they were declared,
+    //so that each default can use the properties before it
+    fn add_properties(
+        &mut self,
+        scope: &Rc<RefCell<Scope>>,
+        properties: &[(String, ASTNode)],
+    ) -> Result<(), GError> {
+        let oscope = self.current_scope.clone();
+        self.current_scope = scope.clone();
+        let res = properties.iter().try_for_each(|(name, prop)| {
+            if let AST::VAR_DEF { value, .. } = &prop.kind {
+                let default = self.visit(value)?;
+                let _ = scope.borrow_mut().add_var(&ASTNode::new(
+                    AST::VAR_DEF {
+                        name: name.clone(),
+                        value: Box::new(default),
+                    },
+                    prop.einfo.clone(),
+                ));
+            }
+            Ok(())
+        });
+        self.current_scope = oscope;
+        res
+    }
+    //finds the scope of the module that a qualified name like 'mod.inner.Thing' refers to,
+    //where module_path would be ["mod", "inner"]
+    fn resolve_module(
+        &self,
+        module_path: &[String],
+        einfo: &ErrorInfo,
+    ) -> Result<Rc<RefCell<Scope>>, GError> {
+        let mut module_scope: Option<Rc<RefCell<Scope>>> = None;
+        for (i, part) in module_path.iter().enumerate() {
+            let vdef = match &module_scope {
+                None => self.current_scope.borrow().resolve_var(part.clone()),
+                Some(scope) => scope.borrow().variables.get(part).cloned(),
+            };
+            module_scope = vdef.and_then(|vdef| match &vdef.borrow().kind {
+                AST::VAR_DEF { value, .. } => match &value.kind {
+                    AST::OBJECT { scope, .. } => Some(scope.clone()),
+                    _ => None,
+                },
+                _ => None,
+            });
+            if module_scope.is_none() {
+                return Err(GError::from_code(
+                    &NOT_A_MODULE,
+                    format!(
+                        "'{}' is not an imported module",
+                        module_path[..=i].join(".")
+                    )
+                    .as_str(),
+                    einfo.clone(),
+                ));
+            }
+        }
+        Ok(module_scope.unwrap_or_else(|| self.current_scope.clone()))
+    }
+    pub fn visit_import(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
         match &node.kind {
             AST::IMPORT {
                 filename,
                 object_name,
+                names,
             } => {
-                let file_prefix = if self.preload {
-                    "std"
-                } else {
-                    "entry"
-                };
-                if &format!("{}/{}", file_prefix, filename) == &node.einfo.file {
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::ImportError,
-                            format!("File import cannot match containing file").as_str(),
+                let Some(source) =
+                    crate::modules::resolve_import(filename, &node.einfo.file, self.std_from_disk)
+                else {
+                    return Err(GError::from_code(
+                            &MODULE_NOT_FOUND,
+                            format!(
+                                "Could not find '{}' next to the importing file or in the module search path",
+                                filename
+                            )
+                            .as_str(),
                             node.einfo.clone(),
                         ));
-                    self.errorstack.borrow().terminate_gs();
-                    return ASTNode::new_noop();
-                }
-                let mut lexer = crate::parsing::lexer::Lexer::new(
-                    &format!("{}/{}", file_prefix, filename.clone()),
-                    Rc::clone(&self.errorstack),
-                );
-                lexer.lex();
-                let mut parser =
-                    crate::parsing::parser::Parser::new(&lexer.tokens, Rc::clone(&self.errorstack));
-                let ast_compound = parser.parse_compound().unwrap();
-                if self.preload {
-                    self.visit(&ast_compound);
-                    return ASTNode::new_noop();
+                };
+                let module_scope = match source {
+                    ModuleSource::File(path) => {
+                        self.load_module(&path.to_string_lossy(), None, &node.einfo)?
+                    }
+                    ModuleSource::Embedded(name, source) => {
+                        self.load_module(&name, Some(source), &node.einfo)?
+                    }
+                };
+                if let Some(names) = names {
+                    for (name, alias) in names {
+                        self.import_name(&module_scope, filename, name, alias, &node.einfo)?;
+                    }
+                    return Ok(ASTNode::new_noop());
                 }
-                let starting_scope = self.current_scope.clone();
-                self.current_scope = Rc::new(RefCell::new(Scope::new(None)));
-                self.visit(&ast_compound);
                 let import_object = ASTNode::new(
                     AST::OBJECT {
                         class_name: "import object".to_string(),
-                        scope: self.current_scope.clone(),
+                        scope: module_scope,
                     },
                     node.einfo.clone(),
                 );
-                self.current_scope = starting_scope;
                 let import_object_def = ASTNode::new(
                     AST::VAR_DEF {
                         name: object_name.clone(),
@@ -1846,40 +2264,288 @@ impl Visitor {
                 );
                 let res = self.current_scope.borrow_mut().add_var(&import_object_def);
                 if let Err(s) = res {
-                    self.errorstack
-                        .borrow_mut()
-                        .errors
-                        .push(GError::new_from_tok(
-                            ETypes::VariableDefinitionError,
-                            s.as_str(),
-                            node.einfo.clone(),
-                        ));
-                    self.errorstack.borrow().terminate_gs();
+                    return Err(GError::from_code(
+                        &VARIABLE_EXISTS,
+                        s.as_str(),
+                        node.einfo.clone(),
+                    ));
                 }
-                ASTNode::new_noop()
+                Ok(ASTNode::new_noop())
             }
-            _ => ASTNode::new_noop(),
+            _ => Ok(ASTNode::new_noop()),
+        }
+    }
+    ///runs every standard library module in the global scope, using the copies embedded in the binary
+    ///or the files in the module search path if std_from_disk is set
+    pub fn preload_std(&mut self) -> Result<(), GError> {
+        for (filename, embedded) in crate::modules::STD_MODULES {
+            let (display_name, source) = if self.std_from_disk {
+                let path = crate::modules::resolve_std(filename);
+                match path.as_ref().map(std::fs::read_to_string) {
+                    Some(Ok(source)) => (path.unwrap().to_string_lossy().to_string(), source),
+                    _ => {
+                        return Err(GError::from_code(
+                                &MISSING_STD_FILE,
+                                format!(
+                                    "Could not load standard library file '{}' from the module search path",
+                                    filename
+                                )
+                                .as_str(),
+                                ErrorInfo::new_empty(),
+                            ));
+                    }
+                }
+            } else {
+                (format!("std/{}", filename), embedded.to_string())
+            };
+            let ast_compound =
+                Parser::parse_source(&display_name, &source, Rc::clone(&self.errorstack));
+            let origin = self.current_scope.clone();
+            self.current_scope = self.global_scope.clone();
+            let res = self.visit(&ast_compound);
+            self.current_scope = origin;
+            res?;
+        }
+        Ok(())
+    }
+    ///adds an error that doesn't stop the script, along with the calls that led to it
+    pub fn report(&self, error: GError) {
+        self.errorstack
+            .borrow_mut()
+            .errors
+            .push(error.with_trace(&self.call_stack));
+    }
+    ///returns to the global scope after a fatal error was recovered from, as the scopes
+    ///that were being visited when it happened were never exited
+    pub fn recover(&mut self) {
+        self.current_scope = self.global_scope.clone();
+        self.import_stack.clear();
+        self.call_stack.clear();
+    }
+    //runs the module at 'path' (or the embedded source of a standard library module) in its own scope and returns that scope
+    //modules that were already loaded are returned from the cache instead of being run again
+    fn load_module(
+        &mut self,
+        path: &str,
+        embedded: Option<&str>,
+        einfo: &ErrorInfo,
+    ) -> Result<Rc<RefCell<Scope>>, GError> {
+        //embedded modules aren't files, so their name is kept as it is
+        let canonical = match embedded {
+            Some(_) => PathBuf::from(path),
+            None => std::fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path)),
+        };
+        if let Some(module_scope) = self.modules.get(&canonical) {
+            return Ok(module_scope.clone());
+        }
+        //the outermost import also records the file it was imported from, so that importing it back is caught
+        let outermost = self.import_stack.is_empty();
+        if outermost {
+            let importer =
+                std::fs::canonicalize(&einfo.file).unwrap_or_else(|_| PathBuf::from(&einfo.file));
+            self.import_stack.push((importer, einfo.file.clone()));
+        }
+        if self.import_stack.iter().any(|(p, _)| *p == canonical) {
+            let mut chain: Vec<&str> = self
+                .import_stack
+                .iter()
+                .map(|(_, name)| name.as_str())
+                .collect();
+            chain.push(path);
+            let error = GError::from_code(
+                &CIRCULAR_IMPORT,
+                format!("Circular import: {}", chain.join(" -> ")).as_str(),
+                einfo.clone(),
+            );
+            if outermost {
+                self.import_stack.clear();
+            }
+            return Err(error);
+        }
+        self.import_stack
+            .push((canonical.clone(), path.to_string()));
+        let reported = self.errorstack.borrow().errors.len();
+        let parsed = match embedded {
+            Some(source) => Ok(Parser::parse_source(
+                path,
+                source,
+                Rc::clone(&self.errorstack),
+            )),
+            None => Parser::parse_file(path, Rc::clone(&self.errorstack)),
+        };
+        let ast_compound = match parsed {
+            //the module's syntax errors are all reported before stopping
+            Ok(_) if self.errorstack.borrow().errors.len() > reported => {
+                self.import_stack.pop();
+                if outermost {
+                    self.import_stack.clear();
+                }
+                return Err(GError::from_code(
+                    &MODULE_HAS_ERRORS,
+                    format!("Could not import '{}' because it has syntax errors", path).as_str(),
+                    einfo.clone(),
+                ));
+            }
+            Ok(ast_compound) => ast_compound,
+            Err(e) => {
+                self.import_stack.pop();
+                if outermost {
+                    self.import_stack.clear();
+                }
+                return Err(e);
+            }
+        };
+        let starting_scope = self.current_scope.clone();
+        let module_scope = Rc::new(RefCell::new(Scope::new(None)));
+        self.current_scope = module_scope.clone();
+        let res = self.visit(&ast_compound);
+        self.current_scope = starting_scope;
+        if let Err(e) = res {
+            self.import_stack.pop();
+            if outermost {
+                self.import_stack.clear();
+            }
+            return Err(e);
+        }
+        self.hide_unexported(&module_scope);
+        self.modules.insert(canonical, module_scope.clone());
+        self.import_stack.pop();
+        if outermost {
+            self.import_stack.clear();
+        }
+        Ok(module_scope)
+    }
+    //once a module uses 'export', everything it didn't export is made private to the module
+    fn hide_unexported(&mut self, module_scope: &Rc<RefCell<Scope>>) {
+        let mut ms = module_scope.borrow_mut();
+        if ms.exported.is_empty() {
+            return;
+        }
+        let hidden: Vec<String> = ms
+            .variables
+            .keys()
+            .chain(ms.functions.keys())
+            .chain(ms.classes.keys())
+            .filter(|name| !ms.exported.contains(*name))
+            .cloned()
+            .collect();
+        ms.private.extend(hidden);
+    }
+    //binds one name from 'import { name as alias } from "file.gsc";' in the current scope
+    fn import_name(
+        &mut self,
+        module_scope: &Rc<RefCell<Scope>>,
+        filename: &str,
+        name: &str,
+        alias: &str,
+        einfo: &ErrorInfo,
+    ) -> Result<(), GError> {
+        let ms = module_scope.borrow();
+        let res = if ms.private.contains(name) {
+            Err((
+                &NOT_EXPORTED,
+                format!("'{}' is not exported by '{}'", name, filename),
+            ))
+        } else if let Some(vdef) = ms.variables.get(name) {
+            let value = match &vdef.borrow().kind {
+                AST::VAR_DEF { value, .. } => value.clone(),
+                _ => Box::new(ASTNode::new_noop()),
+            };
+            let var_def = ASTNode::new(
+                AST::VAR_DEF {
+                    name: alias.to_string(),
+                    value,
+                },
+                einfo.clone(),
+            );
+            self.current_scope
+                .borrow_mut()
+                .add_var(&var_def)
+                .map_err(|s| (&VARIABLE_EXISTS, s))
+        } else if let Some(fdef) = ms.functions.get(name) {
+            let mut fdef = fdef.clone();
+            if let AST::FUNC_DEF { name, .. } = &mut fdef.kind {
+                *name = alias.to_string();
+            }
+            //a function that was itself imported into the module keeps its original home
+            let home = ms
+                .function_homes
+                .get(name)
+                .cloned()
+                .unwrap_or(module_scope.clone());
+            let mut cs = self.current_scope.borrow_mut();
+            let res = cs.add_func(&fdef);
+            if res.is_ok() {
+                cs.function_homes.insert(alias.to_string(), home);
+            }
+            res.map_err(|s| (&FUNCTION_EXISTS, s))
+        } else if let Some(bdef) = ms.classes.get(name) {
+            let mut bdef = bdef.clone();
+            if let AST::CLASS { name, .. } = &mut bdef.kind {
+                *name = alias.to_string();
+            }
+            self.current_scope
+                .borrow_mut()
+                .add_blueprint(&bdef)
+                .map_err(|s| (&BLUEPRINT_EXISTS, s))
+        } else {
+            let names = ms
+                .variables
+                .keys()
+                .chain(ms.functions.keys())
+                .chain(ms.classes.keys())
+                .filter(|n| !ms.private.contains(*n))
+                .map(String::as_str);
+            Err((
+                &NOT_IN_MODULE,
+                with_suggestion(
+                    format!("'{}' does not exist in '{}'", name, filename),
+                    name,
+                    names,
+                ),
+            ))
+        };
+        res.map_err(|(code, s)| GError::from_code(code, s.as_str(), einfo.clone()))
+    }
+    pub fn visit_export(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
+        match &node.kind {
+            AST::EXPORT { body } => {
+                let res = self.visit(body)?;
+                let names = match &body.kind {
+                    AST::VAR_DEF { name, .. }
+                    | AST::FUNC_DEF { name, .. }
+                    | AST::CLASS { name, .. } => vec![name.clone()],
+                    AST::IMPORT {
+                        names: Some(names), ..
+                    } => names.iter().map(|(_, alias)| alias.clone()).collect(),
+                    AST::IMPORT { object_name, .. } => vec![object_name.clone()],
+                    _ => vec![],
+                };
+                self.current_scope.borrow_mut().exported.extend(names);
+                Ok(res)
+            }
+            _ => Ok(ASTNode::new_noop()),
         }
     }
     ///converts primitive string to blueprint _string
-    pub fn wrap_string(&mut self, node: &ASTNode) -> ASTNode {
+    pub fn wrap_string(&mut self, node: &ASTNode) -> Result<ASTNode, GError> {
+        //code in imported modules can't see the global scope, so fall back to the preloaded blueprint
         let blueprint = self
             .current_scope
             .borrow()
             .resolve_blueprint("_string".to_string())
+            .or_else(|| {
+                self.global_scope
+                    .borrow()
+                    .resolve_blueprint("_string".to_string())
+            })
             .unwrap();
         match &blueprint.kind {
-            AST::CLASS {
-                properties,
-                methods,
-                ..
-            } => {
+            AST::CLASS { methods, .. } => {
                 let _string_scope = Rc::new(RefCell::new(Scope::new(Some(Scope::get_root_scope(
                     self.current_scope.clone(),
                 )))));
-                for (_name, prop) in properties {
-                    let _ = _string_scope.borrow_mut().add_var(prop);
-                }
+                self.init_object_scope(&_string_scope, &blueprint)?;
                 let _ = _string_scope.borrow_mut().set_var(
                     "_s".to_string(),
                     &ASTNode::new(
@@ -1893,19 +2559,34 @@ impl Visitor {
                 for (_name, method) in methods {
                     let _ = _string_scope.borrow_mut().add_func(method);
                 }
-                return ASTNode::new(
+                return Ok(ASTNode::new(
                     AST::OBJECT {
                         class_name: "_string".to_string(),
                         scope: _string_scope,
                     },
                     node.einfo.clone(),
-                );
+                ));
             }
             _ => {
-                return ASTNode::new_noop();
+                return Ok(ASTNode::new_noop());
             }
         }
     }
 
     //stdfuncs are now in their own file
 }
+//the names in a chain like 'mod.inner.Thing', or None if it has anything other than names
+fn dotted_names(node: &ASTNode) -> Option<Vec<String>> {
+    match &node.kind {
+        AST::VAR { name } => Some(vec![name.clone()]),
+        AST::OBJECT_INDEX { object, property } => {
+            let mut names = dotted_names(object)?;
+            match &property.kind {
+                AST::VAR { name } => names.push(name.clone()),
+                _ => return None,
+            }
+            Some(names)
+        }
+        _ => None,
+    }
+}
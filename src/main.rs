@@ -1,52 +1,288 @@
 use gscriptrust::lexer::*;
+use gscriptrust::token::*;
 use gscriptrust::error::*;
+use gscriptrust::codes;
+use gscriptrust::warnings::*;
 use gscriptrust::parser::*;
 use gscriptrust::visitor::*;
+use gscriptrust::repl::*;
+use gscriptrust::lint::*;
+use gscriptrust::formatter::*;
+use gscriptrust::lsp::*;
 use std::cell::RefCell;
+use std::io::{IsTerminal, Read, Write};
+use std::path::Path;
 use std::rc::Rc;
 use sdl3::event::Event;
 use sdl3::keyboard::Keycode;
 use std::time::Duration;
 
+const USAGE: &str = "Usage: gscript [options] <command> [arguments]
+
+Commands:
+  run <file> [script args]  Runs a script
+  check <file>              Lexes and parses a script without running it, and reports every error
+  lint <file>               Checks a script for likely mistakes without running it, like unused variables
+  fmt <file>...             Rewrites scripts in the canonical style, keeping their comments
+  tokens <file>             Prints the tokens of a script
+  ast <file>                Prints the parse tree of a script
+  repl                      Starts an interactive session
+  lsp                       Starts a language server for editors, which talks over standard input and output
+  explain <code>            Describes an error code, such as G0104, with an example and a fix
+
+  <file> can be '-' to read the script from standard input, and 'gscript <file>' is short for 'gscript run <file>'
+
+Options:
+  --check                   With 'fmt', lists the scripts that aren't formatted instead of changing them
+  --std-from-disk           Loads the standard library from the module search path instead of the copies in the executable
+  --error-format=<format>   Reports errors and warnings as 'human' readable text (the default) or as 'json', one object per line
+  --allow=<warning>         Stops reporting a warning, or every warning with 'all'
+  --warn=<warning>          Reports a warning without stopping the script (the default)
+  --deny=<warning>          Reports a warning as an error, so the script isn't run
+                            These override gscript.cfg, and the names of the warnings are listed in docs.md
+  -h, --help                Prints this message
+  -V, --version             Prints the version";
+
+//'gscript' is the original name of the 'run' command
+const COMMANDS: [&str; 10] = ["repl", "run", "gscript", "check", "lint", "fmt", "tokens", "ast", "explain", "lsp"];
+
 fn main() {
     std::env::set_var("RUST_BACKTRACE", "full");
 
     //_temp_sdl3_test(); 
 
+    //NO_COLOR (see no-color.org) turns colors off, and so does writing errors somewhere other than a terminal
+    if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) || !std::io::stderr().is_terminal() {
+        colored::control::set_override(false);
+    }
 
-    let args: Vec<String> = std::env::args().collect();
-    
-    if args.len() != 3 {
-        GError::command_line(1, "Expected 2 command line arguments: \n'gscript [filename]'");
-        std::process::exit(1);
+    let mut args = std::env::args().skip(1);
+    let mut std_from_disk = false;
+    let mut check = false;
+    let mut error_format = ErrorFormat::Human;
+    let mut warning_levels = Vec::new();
+    let mut command = None;
+    let mut filename = None;
+    //options can go before or after the command, but everything after the file belongs to the script
+    for arg in args.by_ref() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            "-V" | "--version" => {
+                println!("gscript {}", env!("CARGO_PKG_VERSION"));
+                return;
+            }
+            "--std-from-disk" => std_from_disk = true,
+            "--check" => check = true,
+            "--error-format=human" => error_format = ErrorFormat::Human,
+            "--error-format=json" => error_format = ErrorFormat::Json,
+            _ if arg.starts_with("--allow=") => warning_levels.push((arg["--allow=".len()..].to_string(), Level::Allow)),
+            _ if arg.starts_with("--warn=") => warning_levels.push((arg["--warn=".len()..].to_string(), Level::Warn)),
+            _ if arg.starts_with("--deny=") => warning_levels.push((arg["--deny=".len()..].to_string(), Level::Deny)),
+            _ if arg.starts_with("--error-format") => {
+                usage_error(5, format!("Unknown error format in '{}', expected 'human' or 'json'", arg).as_str());
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                usage_error(3, format!("Unknown option '{}'", arg).as_str());
+            }
+            //lets scripts with a '#!/usr/bin/env gscript' line be executed directly
+            _ if command.is_none() && !COMMANDS.contains(&arg.as_str()) && Path::new(&arg).is_file() => {
+                command = Some(String::from("run"));
+                filename = Some(arg);
+                break;
+            }
+            _ if command.is_none() => command = Some(arg),
+            _ => {
+                filename = Some(arg);
+                break;
+            }
+        }
     }
-    if args[1] != "gscript" {
-        GError::command_line(2, "No support for commands other than 'gscript' has been implemented");
+    let script_args: Vec<String> = args.collect();
+
+    let Some(command) = command else {
+        usage_error(1, "Expected a command");
+    };
+    //gscript.cfg next to the script (or in the current directory) sets the levels, then the flags change them
+    let config_dir = match filename.as_deref() {
+        Some(f) if f != "-" && command != "explain" => Path::new(f).parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new(".")).to_path_buf(),
+        _ => Path::new(".").to_path_buf()
+    };
+    let mut warnings = WarningConfig::new();
+    if let Err(e) = std::path::absolute(&config_dir).map_err(|e| e.to_string()).and_then(|dir| warnings.load(&dir)) {
+        GError::command_line(8, e.as_str());
         std::process::exit(1);
     }
-    let filename = args[2].clone();
+    for (name, level) in &warning_levels {
+        if let Err(e) = warnings.set(name, *level) {
+            usage_error(7, e.as_str());
+        }
+    }
+    if command == "repl" {
+        let mut repl = Repl::new(std_from_disk);
+        repl.set_warnings(warnings);
+        std::process::exit(repl.run());
+    }
+    if command == "lsp" {
+        std::process::exit(LanguageServer::new().run());
+    }
+    if !COMMANDS.contains(&command.as_str()) {
+        usage_error(2, format!("Unknown command '{}'", command).as_str());
+    }
+    if command == "explain" {
+        let Some(code) = filename else {
+            usage_error(1, "Expected an error code for the 'explain' command");
+        };
+        let Some(code) = codes::lookup(&code) else {
+            usage_error(6, format!("Unknown error code '{}'", code).as_str());
+        };
+        println!("{} ({}): {}\n\n{}", code.code, code.etype, code.summary, code.explanation);
+        return;
+    }
+    let Some(filename) = filename else {
+        usage_error(1, format!("Expected a file for the '{}' command", command).as_str());
+    };
+
     let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
-    let mut lexer = Lexer::new(format!("entry/{}", filename).as_str(), Rc::clone(&errorstack));
+    errorstack.borrow_mut().format = error_format;
+    errorstack.borrow_mut().config = warnings;
+    if command == "fmt" {
+        std::process::exit(format_files(std::iter::once(filename).chain(script_args), check, &errorstack));
+    }
+    //the script path is relative to the current directory
+    let mut lexer = if filename == "-" {
+        let mut source = String::new();
+        if let Err(e) = std::io::stdin().read_to_string(&mut source) {
+            GError::command_line(4, format!("Could not read the script from standard input\n  {}", e).as_str());
+            std::process::exit(1);
+        }
+        Lexer::from_source("<stdin>", &source, Rc::clone(&errorstack))
+    } else {
+        Lexer::new(filename.as_str(), Rc::clone(&errorstack)).unwrap_or_else(|e| {
+            errorstack.borrow_mut().errors.push(e);
+            errorstack.borrow().print_dump();
+            std::process::exit(1);
+        })
+    };
     lexer.lex();
+    if command == "tokens" {
+        Token::print_toks(&lexer.tokens);
+        errorstack.borrow().print_dump();
+        std::process::exit(if errorstack.borrow().errors.is_empty() { 0 } else { 1 });
+    }
 
     let mut parser = Parser::new(&lexer.tokens, Rc::clone(&errorstack));
     let ast_compound = parser.parse_compound().unwrap();
+    match command.as_str() {
+        "ast" => {
+            ast_compound.print();
+            errorstack.borrow().print_dump();
+            std::process::exit(if errorstack.borrow().errors.is_empty() { 0 } else { 1 });
+        }
+        "check" => {
+            errorstack.borrow().print_dump();
+            if !errorstack.borrow().errors.is_empty() {
+                std::process::exit(1);
+            }
+            println!("{}: no errors found", if filename == "-" { "<stdin>" } else { filename.as_str() });
+            return;
+        }
+        //a script with syntax errors isn't linted or run at all
+        "lint" if errorstack.borrow().errors.is_empty() => {
+            Linter::lint(&ast_compound, &errorstack);
+            errorstack.borrow().print_dump();
+            if !errorstack.borrow().errors.is_empty() {
+                std::process::exit(1);
+            }
+            if errorstack.borrow().warnings.is_empty() {
+                println!("{}: no problems found", if filename == "-" { "<stdin>" } else { filename.as_str() });
+            }
+            return;
+        }
+        _ if !errorstack.borrow().errors.is_empty() => {
+            errorstack.borrow().print_dump();
+            std::process::exit(1);
+        }
+        _ => {}
+    }
 
     let mut visitor = Visitor::new(Rc::clone(&errorstack));
 
-    //visit GScript standard libraries (string, ..)
-    visitor.preload = true;
-    //string library
-    let string_gsc_root = gscriptrust::ast::ASTNode::new(gscriptrust::ast::AST::IMPORT{filename: "string.gsc".to_string(), object_name: String::new()}, ErrorInfo::new_empty()   );
-    visitor.visit_import(&string_gsc_root);
-
-    //end visit GScript standard libraries
-    visitor.preload = false;
-    
-    visitor.visit(&ast_compound);
+    //visit GScript standard libraries (string, integer, ..)
+    visitor.std_from_disk = std_from_disk;
+    visitor.script_args = script_args;
+    let res = visitor.preload_std().and_then(|_| visitor.visit(&ast_compound));
 
-    let errorstack = visitor.errorstack;
+    //errors are returned up to here, so this is the only place that decides how the process ends
+    let code = match res {
+        Err(e) => match e.exit_code() {
+            Some(code) => code,
+            None => {
+                errorstack.borrow_mut().errors.push(e);
+                1
+            }
+        },
+        Ok(_) => 0
+    };
     errorstack.borrow().print_dump();
+    let _ = std::io::stdout().flush();
+    if code == 0 && !errorstack.borrow().errors.is_empty() {
+        std::process::exit(1);
+    }
+    std::process::exit(code);
+}
+
+//formats each file in place, or with 'check' only reports the ones that would change
+//'-' formats standard input to standard output, returns the exit code
+fn format_files(files: impl Iterator<Item = String>, check: bool, errorstack: &Rc<RefCell<ErrorStack>>) -> i32 {
+    let mut code = 0;
+    for file in files {
+        let source = if file == "-" {
+            let mut source = String::new();
+            std::io::stdin().read_to_string(&mut source).map(|_| source)
+        } else {
+            std::fs::read_to_string(&file)
+        };
+        let source = match source {
+            Ok(source) => source,
+            Err(e) => {
+                GError::command_line(4, format!("Could not read '{}'\n  {}", file, e).as_str());
+                code = 1;
+                continue;
+            }
+        };
+        let name = if file == "-" { "<stdin>" } else { file.as_str() };
+        let Some(formatted) = Formatter::format_source(name, &source, Rc::clone(errorstack)) else {
+            //a file with syntax errors is left as it is
+            errorstack.borrow().print_dump();
+            errorstack.borrow_mut().clear();
+            code = 1;
+            continue;
+        };
+        errorstack.borrow_mut().clear();
+        if check {
+            if formatted != source {
+                println!("{}: not formatted", name);
+                code = 1;
+            }
+        } else if file == "-" {
+            print!("{}", formatted);
+        } else if formatted != source {
+            if let Err(e) = std::fs::write(&file, formatted) {
+                GError::command_line(4, format!("Could not write '{}'\n  {}", file, e).as_str());
+                code = 1;
+            }
+        }
+    }
+    code
+}
+
+fn usage_error(id: i32, message: &str) -> ! {
+    GError::command_line(id, message);
+    eprintln!("\n{}", USAGE);
+    std::process::exit(1);
 }
 
 fn _temp_sdl3_test() {
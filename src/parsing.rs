@@ -1,4 +1,5 @@
 pub mod lexer;
 pub mod token;
 pub mod parser;
+pub mod formatter;
 pub mod ast;
\ No newline at end of file
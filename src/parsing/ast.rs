@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::cell::RefCell;
 
@@ -68,11 +68,21 @@ pub enum AST {
     },
     CLASS {
         name : String,
-        properties : HashMap<String, ASTNode>,
-        methods : HashMap<String, ASTNode>
+        //properties are kept in the order they were declared, since a default can use the ones before it
+        properties : Vec<(String, ASTNode)>,
+        methods : HashMap<String, ASTNode>,
+        getters : HashMap<String, ASTNode>,
+        setters : HashMap<String, ASTNode>,
+        static_properties : Vec<(String, ASTNode)>,
+        static_methods : HashMap<String, ASTNode>,
+        private : HashSet<String>,
+        //scope holding the static members, created when the blueprint definition is visited
+        statics : Option<Rc<RefCell<Scope>>>
     },
     NEW {
         name : String,
+        //names of the module objects the blueprint is qualified by, empty for a blueprint in scope
+        module_path : Vec<String>,
         args : Vec<ASTNode>
     },
     OBJECT {
@@ -101,7 +111,12 @@ pub enum AST {
     },
     IMPORT {
         filename : String,
-        object_name : String
+        object_name : String,
+        //(name, alias) pairs for 'import { name as alias } from "file.gsc";', None when the whole module is bound to object_name
+        names : Option<Vec<(String, String)>>
+    },
+    EXPORT {
+        body : Box<ASTNode>
     },
     NOOP,
     EOF
@@ -123,17 +138,96 @@ impl ASTNode {
         }
     }
     pub fn print(&self) {
+        print!("{}", self.tree());
+    }
+    ///an indented outline of this node and its children, one node per line with its kind, value and line:col
+    pub fn tree(&self) -> String {
+        let mut out = String::new();
+        self.write_tree(&mut out, 0, "");
+        out
+    }
+    fn write_tree(&self, out : &mut String, depth : usize, label : &str) {
+        let desc = match &self.kind {
+            AST::STRING{str_value} => format!("string {:?}", str_value),
+            AST::INT{int_value} => format!("int {}", int_value),
+            AST::FLOAT{float_value} => format!("float {}", float_value),
+            AST::BOOL{bool_value} => format!("bool {}", bool_value),
+            AST::LIST{..} => "list".to_string(),
+            AST::INDEX{..} => "index".to_string(),
+            AST::LIST_REASSIGN{..} => "list reassign".to_string(),
+            AST::OBJECT_REASSIGN{..} => "object reassign".to_string(),
+            AST::BINOP{op, ..} => format!("binop {:?}", op),
+            AST::UNOP{op, ..} => format!("unop {:?}", op),
+            AST::VAR_DEF{name, ..} => format!("var def {}", name),
+            AST::VAR{name} => format!("var {}", name),
+            AST::VAR_REASSIGN{name, ..} => format!("var reassign {}", name),
+            AST::FUNC_DEF{name, ..} => format!("func def {}", name),
+            AST::FUNC_CALL{name, ..} => format!("func call {}", name),
+            AST::RETURN{..} => "return".to_string(),
+            AST::CLASS{name, ..} => format!("blueprint {}", name),
+            AST::NEW{name, module_path, ..} => format!("new {}", module_path.iter().chain([name]).cloned().collect::<Vec<String>>().join(".")),
+            AST::OBJECT{class_name, ..} => format!("object {}", class_name),
+            AST::OBJECT_INDEX{..} => "object index".to_string(),
+            AST::IF{..} => "if".to_string(),
+            AST::WHILE{..} => "while".to_string(),
+            AST::TYPE{type_value} => format!("type {}", type_value),
+            AST::BREAK => "break".to_string(),
+            AST::COMPOUND{..} => "compound".to_string(),
+            AST::IMPORT{filename, object_name, names : None} => format!("import {:?} as {}", filename, object_name),
+            AST::IMPORT{filename, names : Some(names), ..} => format!("import {{{}}} from {:?}", names.iter().map(|(name, alias)| format!("{} as {}", name, alias)).collect::<Vec<String>>().join(", "), filename),
+            AST::EXPORT{..} => "export".to_string(),
+            AST::NOOP => "noop".to_string(),
+            AST::EOF => "eof".to_string(),
+        };
+        out.push_str(&format!("{}{}{} {}:{}\n", "  ".repeat(depth), label, desc, self.einfo.line, self.einfo.col));
+        let depth = depth+1;
         match &self.kind {
-            AST::STRING{str_value} => { println!("string: {}", str_value)},
-            AST::INT{int_value}=>{println!("int: {}",int_value)},
-            AST::FLOAT{float_value}=>{println!("float: {}", float_value)},
-            AST::BOOL{bool_value}=>{println!("bool: {}", bool_value)},
-            AST::BINOP{left, op, right}=>{println!("binop: "); left.to_owned().print(); println!("operator: {:?}", op); right.to_owned().print();}
-            AST::COMPOUND{compound_value}=>{println!("compound, values are:"); compound_value.iter().for_each(|x| x.print());}
-            AST::FUNC_CALL { name, args }=>{println!("func call {}, args are:", name); args.iter().for_each(|x| x.print()); }
-            AST::EOF => {println!("end of file"); }
-            AST::NOOP => {println!("no operation");}
-            _ => {println!("ast print not yet implemented for this ast node type\n({:#?})", self.kind);}
+            AST::LIST{contents} => contents.iter().for_each(|x| x.borrow().write_tree(out, depth, "")),
+            AST::INDEX{target, indices} => {
+                target.write_tree(out, depth, "");
+                indices.iter().for_each(|x| x.write_tree(out, depth, "index: "));
+            }
+            AST::LIST_REASSIGN{target, value} => {target.write_tree(out, depth, ""); value.write_tree(out, depth, "value: ");}
+            AST::OBJECT_REASSIGN{object_index, value} => {object_index.write_tree(out, depth, ""); value.write_tree(out, depth, "value: ");}
+            AST::BINOP{left, right, ..} => {left.write_tree(out, depth, ""); right.write_tree(out, depth, "");}
+            AST::UNOP{body, ..} | AST::EXPORT{body} => body.write_tree(out, depth, ""),
+            AST::VAR_DEF{value, ..} | AST::VAR_REASSIGN{value, ..} | AST::RETURN{value} => value.write_tree(out, depth, ""),
+            AST::FUNC_DEF{body, args, ..} => {
+                args.iter().for_each(|x| x.write_tree(out, depth, "arg: "));
+                body.write_tree(out, depth, "");
+            }
+            AST::FUNC_CALL{args, ..} | AST::NEW{args, ..} => args.iter().for_each(|x| x.write_tree(out, depth, "")),
+            AST::CLASS{properties, methods, getters, setters, static_properties, static_methods, private, ..} => {
+                //properties are printed in the order they were declared, the other members are sorted so the outline is the same on every run
+                let members = [("property", in_order(properties)), ("method", sorted(methods)), ("getter", sorted(getters)), ("setter", sorted(setters)), ("static property", in_order(static_properties)), ("static method", sorted(static_methods))];
+                for (member, list) in members {
+                    for (name, node) in list {
+                        let private = if private.contains(name) { "private " } else { "" };
+                        node.write_tree(out, depth, &format!("{}{} {}: ", private, member, name));
+                    }
+                }
+            }
+            AST::OBJECT_INDEX{object, property} => {object.write_tree(out, depth, ""); property.write_tree(out, depth, "");}
+            AST::IF{conditions, bodies, else_body} => {
+                for (condition, body) in conditions.iter().zip(bodies) {
+                    condition.write_tree(out, depth, "condition: ");
+                    body.write_tree(out, depth, "then: ");
+                }
+                if let Some(else_body) = else_body {
+                    else_body.write_tree(out, depth, "else: ");
+                }
+            }
+            AST::WHILE{condition, body} => {condition.write_tree(out, depth, "condition: "); body.write_tree(out, depth, "body: ");}
+            AST::COMPOUND{compound_value} => compound_value.iter().for_each(|x| x.write_tree(out, depth, "")),
+            _ => {}
         }
     }
+}
+fn in_order(props : &[(String, ASTNode)]) -> Vec<(&String, &ASTNode)> {
+    props.iter().map(|(name, prop)| (name, prop)).collect()
+}
+fn sorted(members : &HashMap<String, ASTNode>) -> Vec<(&String, &ASTNode)> {
+    let mut members : Vec<(&String, &ASTNode)> = members.iter().collect();
+    members.sort_by_key(|(name, _)| *name);
+    members
 }
\ No newline at end of file
@@ -0,0 +1,232 @@
+use super::lexer::*;
+use super::parser::*;
+use super::token::*;
+use crate::errors::error::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+//'gscript fmt', which re-emits a script in the canonical style:
+//  four spaces of indentation, one statement per line, a ';' after every statement,
+//  spaces around binary operators and after commas, and at most one blank line in a row
+//only the whitespace between tokens changes, apart from adding missing semicolons and dropping empty
+//statements, so the formatted script always parses to the same tree
+
+const INDENT : &str = "    ";
+
+pub struct Formatter<'a> {
+    tokens : &'a [Token],
+    comments : &'a [Comment],
+    out : String,
+    indent : usize,
+    //'(' and '[' nesting, nothing inside them is ever put on a new line
+    parens : usize,
+    //whether each open '{' starts a block of statements, rather than the names of an import
+    braces : Vec<bool>,
+    //the last token written, and whether it was a unary operator
+    prev : Option<&'a TokenType>,
+    prev_unary : bool,
+    //the source line of the last token or comment written, to keep blank lines and trailing comments
+    prev_line : usize,
+    newline : bool,
+}
+impl<'a> Formatter<'a> {
+    ///formats 'source', or returns None after adding its syntax errors to the errorstack
+    pub fn format_source(filename : &str, source : &str, errorstack : Rc<RefCell<ErrorStack>>) -> Option<String> {
+        let mut lexer = Lexer::from_source(filename, source, Rc::clone(&errorstack));
+        lexer.lex();
+        Parser::new(&lexer.tokens, Rc::clone(&errorstack)).parse_compound();
+        if !errorstack.borrow().errors.is_empty() {
+            return None;
+        }
+        let mut formatter = Formatter {
+            tokens : &lexer.tokens,
+            comments : &lexer.comments,
+            out : String::new(),
+            indent : 0,
+            parens : 0,
+            braces : Vec::new(),
+            prev : None,
+            prev_unary : false,
+            prev_line : 0,
+            newline : false,
+        };
+        //the lexer skips a '#!' line, so it is copied as it is
+        if source.starts_with("#!") {
+            formatter.out.push_str(source.lines().next().unwrap_or_default().trim_end());
+            formatter.out.push('\n');
+        }
+        formatter.format();
+        //files keep their line endings
+        if source.split_once('\n').is_some_and(|(l, _)| l.ends_with('\r')) {
+            return Some(formatter.out.replace("\r\n", "\n").replace('\n', "\r\n"));
+        }
+        Some(formatter.out)
+    }
+    fn format(&mut self) {
+        let tokens = self.tokens;
+        let mut comments = self.comments.iter().peekable();
+        for (i, tok) in tokens.iter().enumerate() {
+            let closes_block = tok.kind == TokenType::RBR && self.braces.last() == Some(&true);
+            //the last statement of a block or the file doesn't need a ';', but it always gets one
+            if (closes_block || tok.kind == TokenType::EOF) && self.in_statements()
+                && !matches!(self.prev, None | Some(TokenType::SEMI) | Some(TokenType::LBR)) {
+                self.newline = false;
+                self.write_token(&TokenType::SEMI, self.prev_line);
+                self.newline = true;
+            }
+            let mut commented = false;
+            while let Some(comment) = comments.next_if(|c| c.before <= i) {
+                self.write_comment(comment);
+                commented = true;
+            }
+            match tok.kind {
+                TokenType::EOF => break,
+                //a ';' with no statement before it does nothing
+                TokenType::SEMI if self.in_statements() && matches!(self.prev, None | Some(TokenType::SEMI) | Some(TokenType::LBR)) => continue,
+                TokenType::RBR if closes_block => {
+                    self.indent -= 1;
+                    self.braces.pop();
+                    //an empty block stays on one line
+                    self.newline = self.prev != Some(&TokenType::LBR) || commented;
+                }
+                _ => {}
+            }
+            //'} else' and '};' stay on the line of the '}'
+            if self.prev == Some(&TokenType::RBR) && (tok.kind == TokenType::SEMI || tok.kind == TokenType::ID("else".to_string())) {
+                self.newline = false;
+            }
+            self.write_token(&tok.kind, tok.einfo.line);
+            match tok.kind {
+                TokenType::LPR | TokenType::LSQB => self.parens += 1,
+                TokenType::RPR | TokenType::RSQB => self.parens = self.parens.saturating_sub(1),
+                TokenType::LBR => {
+                    let is_block = !matches!(&self.tokens[..i], [.., Token { kind : TokenType::ID(id), .. }] if id == "import" || id == "export");
+                    self.braces.push(is_block);
+                    if is_block {
+                        self.indent += 1;
+                        self.newline = true;
+                    }
+                }
+                TokenType::RBR if closes_block => self.newline = true,
+                TokenType::RBR => {
+                    self.braces.pop();
+                }
+                TokenType::SEMI if self.in_statements() => self.newline = true,
+                _ => {}
+            }
+        }
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+    //whether a ';' here would end a statement, rather than being inside brackets or an import's names
+    fn in_statements(&self) -> bool {
+        self.parens == 0 && self.braces.last() != Some(&false)
+    }
+    fn start_line(&mut self, line : usize) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+            //one blank line is kept between statements, but not at the start or end of a block
+            if line > self.prev_line + 1 && self.prev != Some(&TokenType::LBR) && self.prev_line != 0 {
+                self.out.push('\n');
+            }
+        }
+        self.out.push_str(&INDENT.repeat(self.indent));
+        self.newline = false;
+    }
+    fn write_token(&mut self, kind : &'a TokenType, line : usize) {
+        if kind == &TokenType::RBR && self.newline {
+            //a '}' is never preceded by a blank line
+            self.prev_line = self.prev_line.max(line.saturating_sub(1));
+        }
+        if self.newline && self.parens == 0 || self.out.is_empty() || self.out.ends_with('\n') {
+            self.start_line(line);
+        } else if self.space_before(kind) {
+            self.out.push(' ');
+        }
+        self.out.push_str(&token_text(kind));
+        self.prev_unary = match kind {
+            TokenType::NOT => true,
+            TokenType::MIN => self.prev.is_none_or(|p| p == &TokenType::ID("return".to_string()) || !ends_operand(p)),
+            _ => false
+        };
+        self.prev = Some(kind);
+        self.prev_line = line;
+    }
+    fn write_comment(&mut self, comment : &Comment) {
+        //a comment on the same line as the code before it stays there
+        let trailing = comment.line == self.prev_line && !self.out.is_empty();
+        if (trailing || self.parens > 0) && !self.out.ends_with('\n') {
+            self.out.push(' ');
+        } else {
+            self.start_line(comment.line);
+        }
+        self.out.push_str(&comment.text);
+        self.prev_line = comment.end_line;
+        //whatever follows a comment on its own line starts a new line
+        if !trailing && self.parens == 0 {
+            self.newline = true;
+        }
+    }
+    fn space_before(&self, kind : &TokenType) -> bool {
+        let Some(prev) = self.prev else {
+            return false;
+        };
+        if self.prev_unary || matches!(prev, TokenType::LPR | TokenType::LSQB | TokenType::DOT) {
+            return false;
+        }
+        match kind {
+            TokenType::RPR | TokenType::RSQB | TokenType::CMA | TokenType::SEMI | TokenType::DOT => false,
+            TokenType::RBR => prev != &TokenType::LBR,
+            //calls, definitions and indexing, but not '(' or '[' after an operator or 'return'
+            TokenType::LPR | TokenType::LSQB => !ends_operand(prev) || prev == &TokenType::ID("return".to_string()),
+            _ => true
+        }
+    }
+}
+//whether a '-' after this token subtracts, rather than negating what follows
+fn ends_operand(kind : &TokenType) -> bool {
+    matches!(kind, TokenType::ID(_) | TokenType::INT(_) | TokenType::FLOAT(_) | TokenType::STRING(_) | TokenType::RPR | TokenType::RSQB)
+}
+//the canonical spelling of a token
+fn token_text(kind : &TokenType) -> String {
+    match kind {
+        TokenType::INT(i) => i.to_string(),
+        //always with a '.', so that it is still a float when it is read back
+        TokenType::FLOAT(f) => {
+            let s = f.to_string();
+            if s.contains('.') { s } else { format!("{}.0", s) }
+        }
+        //strings have no escapes, so a string with a '"' in it must have been written with single quotes
+        TokenType::STRING(s) if s.contains('"') => format!("'{}'", s),
+        TokenType::STRING(s) => format!("\"{}\"", s),
+        TokenType::ID(id) => id.clone(),
+        TokenType::DEQL => "==".to_string(),
+        TokenType::EQL => "=".to_string(),
+        TokenType::LTE => "<=".to_string(),
+        TokenType::LT => "<".to_string(),
+        TokenType::GTE => ">=".to_string(),
+        TokenType::GT => ">".to_string(),
+        TokenType::NEQ => "!=".to_string(),
+        TokenType::NOT => "!".to_string(),
+        TokenType::AND => "&".to_string(),
+        TokenType::MOD => "%".to_string(),
+        TokenType::OR => "|".to_string(),
+        TokenType::SEMI => ";".to_string(),
+        TokenType::CLN => ":".to_string(),
+        TokenType::LPR => "(".to_string(),
+        TokenType::RPR => ")".to_string(),
+        TokenType::CMA => ",".to_string(),
+        TokenType::LBR => "{".to_string(),
+        TokenType::RBR => "}".to_string(),
+        TokenType::LSQB => "[".to_string(),
+        TokenType::RSQB => "]".to_string(),
+        TokenType::PLS => "+".to_string(),
+        TokenType::MIN => "-".to_string(),
+        TokenType::MUL => "*".to_string(),
+        TokenType::DIV => "/".to_string(),
+        TokenType::DOT => ".".to_string(),
+        TokenType::RARW => "->".to_string(),
+        TokenType::EOF => String::new(),
+    }
+}
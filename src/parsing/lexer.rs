@@ -1,6 +1,8 @@
 use super::token::*;
 use std::fs;
+use crate::errors::codes::*;
 use crate::errors::error::*;
+use crate::errors::warnings::*;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -8,6 +10,7 @@ use std::rc::Rc;
 pub struct Lexer {
     filename : String,
     pub tokens : Vec<Token>,
+    pub comments : Vec<Comment>,
     source :  Vec<char>,
     sourcelines : Vec<String>,
     curri : usize,
@@ -16,23 +19,28 @@ pub struct Lexer {
     errorstack : Rc<RefCell<ErrorStack>>
 }
 impl Lexer {
-    pub fn new(filename : &str, errorstack : Rc<RefCell<ErrorStack>>) -> Lexer {
-        let filename = filename.to_string();
-        let s: String = fs::read_to_string(&filename).unwrap_or_else(|e| {
-            errorstack.borrow_mut().errors.push(GError::new(ETypes::FileError, &format!("(query file {})\n  {}", filename.clone(), &e.to_string()), String::new(), String::from("lexer_file_error"), 0, 0, 0));
-            errorstack.borrow_mut().terminate_gs();
-            std::process::exit(1);
-        });
-        Lexer { filename, tokens : Vec::new(), source : s.chars().collect(), sourcelines : s.split('\n').map(|s| s.to_string()).collect(),  curri : 0, currline : 1, currchar : 1, errorstack, }
+    ///reads the file at 'filename', returning the error instead of reporting it so that the caller decides what a missing file means
+    pub fn new(filename : &str, errorstack : Rc<RefCell<ErrorStack>>) -> Result<Lexer, GError> {
+        match fs::read_to_string(filename) {
+            Ok(s) => Ok(Lexer::from_source(filename, &s, errorstack)),
+            Err(e) => Err(GError::from_code(&CANNOT_READ_FILE, &format!("(query file {})\n  {}", filename, &e.to_string()), ErrorInfo::new(String::new(), String::from("lexer_file_error"), 0, 0, 0))),
+        }
+    }
+    ///lexes source text that didn't come from reading a file, filename is only used in error messages
+    pub fn from_source(filename : &str, s : &str, errorstack : Rc<RefCell<ErrorStack>>) -> Lexer {
+        Lexer { filename : filename.to_string(), tokens : Vec::new(), comments : Vec::new(), source : s.chars().collect(), sourcelines : s.split('\n').map(|s| s.to_string()).collect(),  curri : 0, currline : 1, currchar : 1, errorstack, }
     }
     pub fn lex(&mut self) {
+        if self.source.starts_with(&['#', '!']) {
+            self.skip_shebang();
+        }
         while let Some(&c) = self.source.get(self.curri) {
             if c.is_whitespace() {
                 self.skip_space();
                 continue;
             } 
             if c == '\\' {
-                self.skip_comments();
+                self.collect_comment();
                 continue;
             }  
             if c == '"' || c == '\'' {
@@ -69,7 +77,7 @@ impl Lexer {
                 '*' => { self.tokens.push(Token::new(TokenType::MUL, ErrorInfo::new(self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, self.currchar, self.currchar+1)));}
                 '/' => { self.tokens.push(Token::new(TokenType::DIV, ErrorInfo::new(self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, self.currchar, self.currchar+1)));}
                 '.' => { self.tokens.push(Token::new(TokenType::DOT, ErrorInfo::new(self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, self.currchar, self.currchar+1)));}
-                _ => { self.errorstack.borrow_mut().errors.push(GError::new(ETypes::TokenError, "Unrecognized token", self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, self.currchar, self.currchar+1));}
+                _ => { self.errorstack.borrow_mut().errors.push(GError::from_code(&UNRECOGNIZED_CHARACTER, "Unrecognized token", ErrorInfo::new(self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, self.currchar, self.currchar+1)));}
             }
             self.curri += 1;
             self.currchar += 1;
@@ -89,19 +97,35 @@ impl Lexer {
             self.currchar += 1;
         }
     }
-    fn skip_comments(&mut self) {
-        self.curri += 1;
-        self.currchar += 1;
+    fn skip_shebang(&mut self) {
         while let Some(&c) = self.source.get(self.curri) {
-            if c == '\\' {
-                self.curri += 1;
-                self.currchar += 1;
+            if c == '\n' {
                 return;
             }
             self.curri += 1;
             self.currchar += 1;
         }
     }
+    //comments are kept apart from the tokens, so only the formatter has to deal with them
+    fn collect_comment(&mut self) {
+        let line = self.currline;
+        let mut text = vec!['\\'];
+        self.curri += 1;
+        self.currchar += 1;
+        while let Some(&c) = self.source.get(self.curri) {
+            text.push(c);
+            self.curri += 1;
+            self.currchar += 1;
+            if c == '\\' {
+                break;
+            }
+            if c == '\n' {
+                self.currline += 1;
+                self.currchar = 1;
+            }
+        }
+        self.comments.push(Comment { text : text.iter().collect(), before : self.tokens.len(), line, end_line : self.currline });
+    }
     // -- TODO --
     // fix issue with collecting string with a forgotten closing quote
     fn collect_str(&mut self, schar: char) {
@@ -116,8 +140,7 @@ impl Lexer {
                 self.tokens.push(Token::new(TokenType::STRING(s.iter().collect()), ErrorInfo::new(self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, starting_c, self.currchar+1)));
                 return;
             } else if c == '\n' {
-                self.errorstack.borrow_mut().flag(EFlags::CloseString);
-                self.errorstack.borrow().warn(ErrorInfo::new(self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, starting_c, self.currchar+1), "Did you mean to close this string?");
+                self.errorstack.borrow_mut().warn(Warning::UnclosedString, ErrorInfo::new(self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, starting_c, self.currchar+1), "Did you mean to close this string?");
             }
             s.push(c);
             self.curri += 1;
@@ -143,7 +166,7 @@ impl Lexer {
                 if dot {
                     let num : f32 = snum.parse().unwrap_or_else( |_| {
                         self.errorstack.borrow_mut().errors.push(
-                            GError::new(ETypes::SyntaxError, "Invalid syntax for creating a float", self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, starting_c, self.currchar )
+                            GError::from_code(&INVALID_NUMBER, "Invalid syntax for creating a float", ErrorInfo::new(self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, starting_c, self.currchar))
                         );
                         return 0.0;
                     });
@@ -152,7 +175,7 @@ impl Lexer {
                 } else {
                     let num : i32 = snum.parse().unwrap_or_else( |_| {
                         self.errorstack.borrow_mut().errors.push(
-                            GError::new(ETypes::SyntaxError, "Invalid syntax for creating an int", self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, starting_c, self.currchar )
+                            GError::from_code(&INVALID_NUMBER, "Invalid syntax for creating an int", ErrorInfo::new(self.filename.clone(), self.sourcelines.get(self.currline-1).unwrap().to_string(), self.currline, starting_c, self.currchar))
                         );
                         return 0;
                     });
@@ -1,8 +1,11 @@
+use crate::errors::codes::*;
 use crate::errors::error::*;
+use crate::errors::warnings::*;
 use super::ast::*;
 use super::token::*;
+use super::lexer::Lexer;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 pub struct Parser<'a> {
@@ -11,43 +14,111 @@ pub struct Parser<'a> {
     token_i : usize,
     tokens : &'a Vec<Token>,
     errorstack : Rc<RefCell<ErrorStack>>,
+    //set after a syntax error until the rest of the broken statement has been skipped
+    panicking : bool,
+    //how many compounds are being parsed, 1 at the top level of a file
+    nesting : usize,
 }
 impl<'a> Parser<'a> {
     pub fn new(tokens : &'a Vec<Token>, errorstack : Rc<RefCell<ErrorStack>>) -> Parser<'a> {
-        Parser { prev_token: None, curr_token: tokens.get(0), token_i : 0, tokens, errorstack }
+        Parser { prev_token: None, curr_token: tokens.get(0), token_i : 0, tokens, errorstack, panicking : false, nesting : 0 }
+    }
+    ///lexes and parses source text that is already in memory, any errors are collected in errorstack
+    ///name is what errors report as the file
+    pub fn parse_source(name : &str, source : &str, errorstack : Rc<RefCell<ErrorStack>>) -> ASTNode {
+        let mut lexer = Lexer::from_source(name, source, Rc::clone(&errorstack));
+        lexer.lex();
+        let mut parser = Parser::new(&lexer.tokens, errorstack);
+        parser.parse_compound().unwrap_or_else(ASTNode::new_noop)
+    }
+    ///reads, lexes and parses the file at 'filename', failing only when the file can't be read
+    pub fn parse_file(filename : &str, errorstack : Rc<RefCell<ErrorStack>>) -> Result<ASTNode, GError> {
+        let mut lexer = Lexer::new(filename, Rc::clone(&errorstack))?;
+        lexer.lex();
+        let mut parser = Parser::new(&lexer.tokens, errorstack);
+        Ok(parser.parse_compound().unwrap_or_else(ASTNode::new_noop))
     }
     pub fn advance(&mut self) {
         self.token_i += 1;
         self.prev_token = self.curr_token;
         self.curr_token = self.tokens.get(self.token_i);
     }
-    pub fn verify(&mut self, comparison : TokenType) {
-        if self.curr_token.unwrap().kind != comparison {
-            self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::TokenError, format!("Expected token {:?} but received {:?}", comparison, self.curr_token.unwrap().kind ).as_str(), self.curr_token.unwrap().einfo.clone()));
-            self.errorstack.borrow().terminate_gs();
+    pub fn verify(&mut self, comparison : TokenType) -> Option<()> {
+        let tok = self.curr_token?;
+        if tok.kind != comparison {
+            self.error(&EXPECTED_TOKEN, format!("Expected token {:?} but received {:?}", comparison, tok.kind).as_str(), tok.einfo.clone());
+            return None;
         }
-    } 
-    //DONE
-    pub fn parse_compound(&mut self) -> Option<ASTNode> {
-        let mut comp_ast = ASTNode::new(AST::COMPOUND { 
-            compound_value: ( Vec::new()) 
-        }, self.curr_token.unwrap().einfo.clone());
-        match comp_ast.kind {
-            AST::COMPOUND { ref mut compound_value } => { compound_value.push(self.parse_comp_expr().unwrap_or(ASTNode::new(AST::NOOP, self.curr_token.unwrap().einfo.clone()))) },
-            _ => { return None }
+        Some(())
+    }
+    ///records a syntax error and enters panic mode, where further errors aren't recorded
+    ///as they are most likely caused by the first one
+    fn error(&mut self, code : &'static ErrorCode, message : &str, einfo : ErrorInfo) {
+        if !self.panicking {
+            self.errorstack.borrow_mut().errors.push(GError::from_code(code, message, einfo));
         }
+        self.panicking = true;
+    }
+    //leaves panic mode by skipping to the ';' or '}' that ends the broken statement, stepping over any blocks inside it
+    fn synchronize(&mut self) {
+        let mut depth = 0;
         while let Some(tok) = self.curr_token {
-            if tok.kind != TokenType::SEMI {
+            match tok.kind {
+                TokenType::EOF => break,
+                TokenType::SEMI | TokenType::RBR if depth == 0 => break,
+                TokenType::LBR => depth += 1,
+                TokenType::RBR => depth -= 1,
+                _ => {}
+            }
+            self.advance();
+        }
+        self.panicking = false;
+    }
+    //for places where an expression can't be left out, like the right side of an operator
+    fn parse_required(&mut self, parse : fn(&mut Parser<'a>) -> Option<ASTNode>) -> Option<ASTNode> {
+        let node = parse(self);
+        if node.is_none() {
+            let tok = self.curr_token.or(self.prev_token)?;
+            self.error(&EXPECTED_EXPRESSION, format!("Expected an expression but received {:?}", tok.kind).as_str(), tok.einfo.clone());
+        }
+        node
+    }
+    //DONE
+    ///a syntax error only skips the statement it's in, so that one run reports every error in the file
+    pub fn parse_compound(&mut self) -> Option<ASTNode> {
+        let e = self.curr_token?.einfo.clone();
+        let mut compound_value = Vec::new();
+        self.nesting += 1;
+        loop {
+            let statement = self.parse_comp_expr();
+            if self.panicking {
+                self.synchronize();
+            }
+            let Some(tok) = self.curr_token else {
+                compound_value.push(statement.unwrap_or(ASTNode::new_noop()));
                 break;
-            } else {
-                self.advance();
-                match comp_ast.kind {
-                    AST::COMPOUND { ref mut compound_value } => { compound_value.push(self.parse_comp_expr().unwrap_or(ASTNode::new(AST::NOOP, self.curr_token.unwrap().einfo.clone()))) },
-                    _ => { return None }
+            };
+            compound_value.push(statement.unwrap_or(ASTNode::new(AST::NOOP, tok.einfo.clone())));
+            match tok.kind {
+                TokenType::SEMI => self.advance(),
+                TokenType::EOF => break,
+                TokenType::RBR if self.nesting > 1 => break,
+                TokenType::RBR => {
+                    self.error(&UNMATCHED_BRACE, "Unexpected '}' with no block to close", tok.einfo.clone());
+                    self.panicking = false;
+                    self.advance();
+                }
+                _ => {
+                    self.error(&EXPECTED_SEMICOLON, format!("Expected ';' but received {:?}", tok.kind).as_str(), tok.einfo.clone());
+                    self.synchronize();
+                    if self.curr_token.is_some_and(|t| t.kind == TokenType::SEMI) {
+                        self.advance();
+                    }
                 }
             }
         }
-        Some(comp_ast)
+        self.nesting -= 1;
+        Some(ASTNode::new(AST::COMPOUND { compound_value }, e))
     }
     //DONE
     pub fn parse_comp_expr(&mut self) -> Option<ASTNode> {
@@ -57,7 +128,7 @@ impl<'a> Parser<'a> {
                     TokenType::AND | TokenType::OR => {
                         let op = tok.kind.clone();
                         self.advance();
-                        let right = self.parse_comp_term().unwrap_or(ASTNode::new_noop());
+                        let right = self.parse_required(Parser::parse_comp_term)?;
 
                         let ast_binop = AST::BINOP {
                             left : Box::new(ast_left),
@@ -84,7 +155,7 @@ impl<'a> Parser<'a> {
                     | TokenType::GTE | TokenType::NEQ => {
                         let op = tok.kind.clone();
                         self.advance();
-                        let right = self.parse_expr().unwrap();
+                        let right = self.parse_required(Parser::parse_expr)?;
 
                         let ast_binop = AST::BINOP {
                             left : Box::new(ast_left),
@@ -113,14 +184,12 @@ impl<'a> Parser<'a> {
                 TokenType::INT(_) | TokenType::FLOAT(_) => { atom = self.parse_num(); }
                 TokenType::STRING(_) => { atom = self.parse_string(); }
                 TokenType::ID(_) => { return self.parse_identifier(); }
-                TokenType::EOF => { return Some(ASTNode::new(AST::EOF, self.curr_token.unwrap().einfo.clone())); }
+                TokenType::EOF => { return Some(ASTNode::new(AST::EOF, tok.einfo.clone())); }
                 TokenType::LPR => {
                     self.advance();
-                    let ast = self.parse_comp_expr();
+                    let ast = self.parse_required(Parser::parse_comp_expr);
                     if self.curr_token?.kind != TokenType::RPR {
-                        //err
-                        self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::SyntaxError, "Expected ')'", self.curr_token?.einfo.clone()));
-                        self.errorstack.borrow().terminate_gs();
+                        self.error(&EXPECTED_CLOSING_PAREN, "Expected ')'", self.curr_token?.einfo.clone());
                         atom = None;
                     } else {
                         self.advance();
@@ -134,16 +203,20 @@ impl<'a> Parser<'a> {
                     self.advance();
                     // -- TODO --
                     //handle invalid negative number error
-                    let ast_body = self.parse_mono().unwrap_or(ASTNode::new_noop());
+                    let ast_body = self.parse_required(Parser::parse_mono)?;
                     atom = Some(ASTNode::new(AST::UNOP { op: TokenType::MIN, body: Box::new(ast_body)}, tok.einfo.clone()));
                 }
                 TokenType::NOT => {
                     self.advance();
-                    let ast_body = self.parse_mono().unwrap_or(ASTNode::new_noop());
+                    let ast_body = self.parse_required(Parser::parse_mono)?;
                     atom = Some(ASTNode::new(AST::UNOP { op: TokenType::NOT, body: Box::new(ast_body)}, tok.einfo.clone()));
                 }
-                
-                _ => {atom = None;}
+                //an empty statement, list or argument list
+                TokenType::SEMI | TokenType::RBR | TokenType::RPR | TokenType::RSQB | TokenType::CMA => { atom = None; }
+                _ => {
+                    self.error(&UNEXPECTED_TOKEN, format!("Unexpected token {:?}", tok.kind).as_str(), tok.einfo.clone());
+                    atom = None;
+                }
             }
             match atom {
                 None => atom,
@@ -169,7 +242,7 @@ impl<'a> Parser<'a> {
                     TokenType::MUL | TokenType::DIV | TokenType::MOD => {
                         let op = tok.kind.clone();
                         self.advance();
-                        let right = self.parse_mono().unwrap();
+                        let right = self.parse_required(Parser::parse_mono)?;
 
                         let ast_binop = AST::BINOP {
                             left : Box::new(ast_left),
@@ -197,7 +270,7 @@ impl<'a> Parser<'a> {
                     TokenType::PLS | TokenType::MIN => {
                         let op = tok.kind.clone();
                         self.advance();
-                        let right = self.parse_term().unwrap_or(ASTNode::new_noop());
+                        let right = self.parse_required(Parser::parse_term)?;
 
                         let ast_binop = AST::BINOP {
                             left : Box::new(ast_left),
@@ -228,6 +301,7 @@ impl<'a> Parser<'a> {
                     "while" => self.parse_while(),
                     "break" => self.parse_break(),
                     "import" => self.parse_import(),
+                    "export" => self.parse_export(),
                     "true" => {
                         let res = Some(ASTNode::new(AST::BOOL{ bool_value : true }, self.curr_token?.einfo.clone()));
                         self.advance();
@@ -246,21 +320,89 @@ impl<'a> Parser<'a> {
     }
     pub fn parse_import(&mut self) -> Option<ASTNode> {
         self.advance();
+        if self.curr_token?.kind == TokenType::LBR {
+            return self.parse_import_names();
+        }
         let fname: String = match &self.curr_token?.kind {
             TokenType::STRING(filename) => { filename.clone() },
-            _ => { "".to_string() }
+            _ => {
+                self.error(&INVALID_IMPORT, "Expected the name of the imported file in quotes", self.curr_token?.einfo.clone());
+                return None;
+            }
         };
         self.advance();
-        self.verify(TokenType::RARW);
+        self.verify(TokenType::RARW)?;
         self.advance();
         let oname: String = match &self.curr_token?.kind {
             TokenType::ID(object_name) => {object_name.clone()},
-            _ => { "".to_string() }
+            _ => {
+                self.error(&INVALID_IMPORT, "Expected a name for the imported module after '->'", self.curr_token?.einfo.clone());
+                return None;
+            }
         };
         self.advance();
         self._warn_semi();  
         
-        Some(ASTNode::new(AST::IMPORT{ filename: fname, object_name: oname}, self.curr_token?.einfo.clone()))
+        Some(ASTNode::new(AST::IMPORT{ filename: fname, object_name: oname, names: None}, self.curr_token?.einfo.clone()))
+    }
+    //parses '{ foo, Bar as B } from "file.gsc"', the rest of a selective import or a re-export
+    fn parse_import_names(&mut self) -> Option<ASTNode> {
+        self.verify(TokenType::LBR)?;
+        self.advance();
+        let mut names = Vec::new();
+        while let TokenType::ID(name) = &self.curr_token?.kind {
+            let name = name.clone();
+            self.advance();
+            let mut alias = name.clone();
+            if self.curr_token?.kind == TokenType::ID("as".to_string()) {
+                self.advance();
+                match &self.curr_token?.kind {
+                    TokenType::ID(a) => alias = a.clone(),
+                    _ => {
+                        self.error(&INVALID_IMPORT, "Expected a name after 'as'", self.curr_token?.einfo.clone());
+                        return None;
+                    }
+                }
+                self.advance();
+            }
+            names.push((name, alias));
+            if self.curr_token?.kind != TokenType::CMA {
+                break;
+            }
+            self.advance();
+        }
+        self.verify(TokenType::RBR)?;
+        self.advance();
+        if self.curr_token?.kind != TokenType::ID("from".to_string()) {
+            self.error(&INVALID_IMPORT, "Expected 'from' after the list of imported names", self.curr_token?.einfo.clone());
+            return None;
+        }
+        self.advance();
+        let fname: String = match &self.curr_token?.kind {
+            TokenType::STRING(filename) => { filename.clone() },
+            _ => {
+                self.error(&INVALID_IMPORT, "Expected the name of the imported file in quotes", self.curr_token?.einfo.clone());
+                return None;
+            }
+        };
+        self.advance();
+        self._warn_semi();
+
+        Some(ASTNode::new(AST::IMPORT{ filename: fname, object_name: String::new(), names: Some(names)}, self.curr_token?.einfo.clone()))
+    }
+    //'export' can be put before a variable, function, blueprint or import, and 'export { a, b as c } from "file.gsc";' re-exports names from another module
+    pub fn parse_export(&mut self) -> Option<ASTNode> {
+        let e = self.curr_token?.einfo.clone();
+        self.advance(); //past 'export'
+        let body = match &self.curr_token?.kind {
+            TokenType::LBR => self.parse_import_names(),
+            TokenType::ID(x) if ["assign", "funct", "blueprint", "import"].contains(&x.as_str()) => self.parse_identifier(),
+            _ => {
+                self.error(&INVALID_EXPORT, "Expected a variable, function, blueprint or import after 'export'", self.curr_token?.einfo.clone());
+                None
+            }
+        };
+        Some(ASTNode::new(AST::EXPORT{ body: Box::new(body.unwrap_or(ASTNode::new_noop())) }, e))
     }
     //DONE
     pub fn parse_variable_definition(&mut self) -> Option<ASTNode> {
@@ -270,14 +412,17 @@ impl<'a> Parser<'a> {
                 let var_name = name;
                 let e = self.curr_token?.einfo.clone();
                 self.advance();
-                self.verify(TokenType::EQL);
+                self.verify(TokenType::EQL)?;
                 self.advance();
-                let var_value = self.parse_comp_expr()?;
+                let var_value = self.parse_required(Parser::parse_comp_expr)?;
                 let var_def = ASTNode::new(AST::VAR_DEF { name: var_name.to_string(), value: Box::new(var_value) }, e.clone());
                 self._warn_semi();
                 return Some(var_def);
             }
-            _ => None 
+            _ => {
+                self.error(&EXPECTED_NAME, "Expected a variable name after 'assign'", self.curr_token?.einfo.clone());
+                None
+            }
         }
     }
     //DONE
@@ -309,7 +454,12 @@ impl<'a> Parser<'a> {
                 if self.curr_token?.kind == TokenType::EQL {
                     return self.parse_list_reassign(list_ind_expr);
                 } else if self.curr_token?.kind == TokenType::DOT {
-                    return self.parse_obj_index(list_ind_expr);
+                    let obj_ind_expr = self.parse_obj_index(list_ind_expr)?;
+                    //maybe it's var[1].x = 5
+                    if self.curr_token?.kind == TokenType::EQL {
+                        return self.parse_obj_reassign(obj_ind_expr);
+                    }
+                    return Some(obj_ind_expr);
                 } else {
                     return Some(list_ind_expr);
                 }
@@ -345,7 +495,7 @@ impl<'a> Parser<'a> {
                     self.advance();
                 },
                 _ => {
-                    //expected identifier as property error
+                    self.error(&EXPECTED_NAME, "Expected a property name after '.'", self.curr_token?.einfo.clone());
                     return None;
                 }
             }
@@ -361,7 +511,7 @@ impl<'a> Parser<'a> {
     }
     pub fn parse_obj_reassign(&mut self, object_index : ASTNode) -> Option<ASTNode> {
         self.advance(); //past 'EQL'
-        let value = self.parse_comp_expr().unwrap_or(ASTNode::new_noop());
+        let value = self.parse_required(Parser::parse_comp_expr)?;
         let e = value.einfo.clone();
         Some(ASTNode::new(AST::OBJECT_REASSIGN{object_index : Box::new(object_index), value : Box::new(value)  }, e))
     }
@@ -372,7 +522,7 @@ impl<'a> Parser<'a> {
             _ => String::new()
         };
         self.advance(); //past the EQL
-        let var_value = self.parse_comp_expr()?;
+        let var_value = self.parse_required(Parser::parse_comp_expr)?;
         Some(ASTNode::new(AST::VAR_REASSIGN { name: var_name, value: Box::new(var_value) }, self.prev_token?.einfo.clone()))
     }
     pub fn parse_list(&mut self) -> Option<ASTNode> {
@@ -384,7 +534,7 @@ impl<'a> Parser<'a> {
             self.advance();
             contents.push(Rc::new(RefCell::new(self.parse_comp_expr().unwrap_or(ASTNode::new_noop()))));
         }
-        self.verify(TokenType::RSQB);
+        self.verify(TokenType::RSQB)?;
         self.advance();
         Some(ASTNode::new(AST::LIST{contents}, e))
     }
@@ -393,15 +543,15 @@ impl<'a> Parser<'a> {
         let mut inds = Vec::new();
         while self.curr_token?.kind == TokenType::LSQB {
             self.advance();
-            inds.push(self.parse_expr().unwrap_or(ASTNode::new_noop()));
-            self.verify(TokenType::RSQB);
+            inds.push(self.parse_required(Parser::parse_expr)?);
+            self.verify(TokenType::RSQB)?;
             self.advance();
         }
         Some(ASTNode::new(AST::INDEX{target : Box::new(target), indices: inds}, e))
     }
     pub fn parse_list_reassign(&mut self, target : ASTNode) -> Option<ASTNode> {
         self.advance(); //past the EQL
-        let value = self.parse_comp_expr().unwrap_or(ASTNode::new_noop());
+        let value = self.parse_required(Parser::parse_comp_expr)?;
         let e = value.einfo.clone();
         Some(ASTNode::new(AST::LIST_REASSIGN { target: Box::new(target), value: Box::new(value) }, e))
     }
@@ -410,41 +560,47 @@ impl<'a> Parser<'a> {
         self.advance(); //past the 'funct'
         let func_name = match &self.curr_token?.kind {
             TokenType::ID(x) => x.clone(),
-            _ => String::new()
+            _ => {
+                self.error(&EXPECTED_NAME, "Expected a function name", self.curr_token?.einfo.clone());
+                return None;
+            }
         };
         let e = self.curr_token?.einfo.clone();
         self.advance();
-        self.verify(TokenType::LPR);
+        self.verify(TokenType::LPR)?;
         self.advance();
         let mut func_args: Vec<ASTNode> = Vec::new();
         if self.curr_token?.kind != TokenType::RPR {
-            func_args.push(self.parse_function_param().unwrap_or(ASTNode::new_noop()));
+            func_args.push(self.parse_function_param()?);
         }
         while let Some(tok) = self.curr_token {
             if tok.kind != TokenType::CMA {
                 break;
             } else {
                 self.advance();
-                func_args.push(self.parse_function_param().unwrap_or(ASTNode::new_noop()));
+                func_args.push(self.parse_function_param()?);
             }
         }
-        self.verify(TokenType::RPR);
+        self.verify(TokenType::RPR)?;
         self.advance();
-        self.verify(TokenType::LBR);
+        self.verify(TokenType::LBR)?;
         self.advance();
         let func_body = self.parse_compound()?;
-        self.verify(TokenType::RBR);
+        self.verify(TokenType::RBR)?;
         self.advance();
         self._warn_semi();
         Some(ASTNode::new(AST::FUNC_DEF { body: Box::new(func_body), name: func_name, args: func_args }, e))
     }
     //DONE
     pub fn parse_function_param(&mut self) -> Option<ASTNode> {
-        self.verify(TokenType::ID("param".to_string()));
+        self.verify(TokenType::ID("param".to_string()))?;
         self.advance();
         let param_name = match &self.curr_token?.kind {
             TokenType::ID(x) => x.clone(),
-            _ => String::new()
+            _ => {
+                self.error(&EXPECTED_NAME, "Expected a parameter name after 'param'", self.curr_token?.einfo.clone());
+                return None;
+            }
         };
         self.advance();
         Some(ASTNode::new(AST::VAR_DEF{name:param_name, value: Box::new(ASTNode::new_noop())}, self.curr_token?.einfo.clone()))
@@ -458,17 +614,17 @@ impl<'a> Parser<'a> {
                     let mut func_args = Vec::new();
                     self.advance();
                     if self.curr_token?.kind != TokenType::RPR {
-                        func_args.push(self.parse_comp_expr().unwrap_or(ASTNode::new_noop()));
+                        func_args.push(self.parse_required(Parser::parse_comp_expr)?);
                         while let Some(curr_tok) = self.curr_token {
                             if curr_tok.kind != TokenType::CMA {
                                 break;
                             } else {
                                 self.advance();
-                                func_args.push(self.parse_comp_expr().unwrap_or(ASTNode::new_noop()));
+                                func_args.push(self.parse_required(Parser::parse_comp_expr)?);
                             }
                         }
                     }
-                    self.verify(TokenType::RPR);
+                    self.verify(TokenType::RPR)?;
                     self.advance();
                     Some(ASTNode::new(AST::FUNC_CALL {
                         name : func_name.to_string(), args : func_args
@@ -492,116 +648,193 @@ impl<'a> Parser<'a> {
             TokenType::ID(x) =>  x.clone(),
             _ => {
                 //invalid blueprint name error
-                self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::SyntaxError, "Expected name of blueprint", self.curr_token?.einfo.clone()));
-                self.errorstack.borrow().terminate_gs();
-                String::new()
+                self.error(&EXPECTED_NAME, "Expected name of blueprint", self.curr_token?.einfo.clone());
+                return None;
             }
         };
         let e = self.curr_token?.einfo.clone();
         self.advance();
-        self.verify(TokenType::LBR);
+        self.verify(TokenType::LBR)?;
         self.advance();
-        let mut properties = HashMap::new();
+        let mut properties = Vec::new();
         let mut methods = HashMap::new();
+        let mut getters = HashMap::new();
+        let mut setters = HashMap::new();
+        let mut static_properties = Vec::new();
+        let mut static_methods = HashMap::new();
+        let mut private = HashSet::new();
         while self.curr_token?.kind != TokenType::RBR {
+            //'private' can prefix either a prop or a method
+            let mut is_private = false;
+            if self.curr_token?.kind == TokenType::ID("private".to_string()) {
+                is_private = true;
+                self.advance();
+            }
+            //'static' members belong to the blueprint itself instead of each instance
+            let mut is_static = false;
+            if self.curr_token?.kind == TokenType::ID("static".to_string()) {
+                is_static = true;
+                self.advance();
+            }
             if let TokenType::ID(id_value) = &self.curr_token?.kind {
                 match id_value.as_str() {
                     "prop" =>  {
                         self.advance();
                         if let TokenType::ID(prop_name) = &self.curr_token?.kind {
-                            properties.insert(prop_name.clone(), ASTNode::new(AST::VAR_DEF { name: prop_name.clone(), value: Box::new(ASTNode::new_noop()) }, self.curr_token?.einfo.clone()));
+                            let prop_e = self.curr_token?.einfo.clone();
                             self.advance();
-                            self.verify(TokenType::SEMI);
+                            //properties can have a default value, which is evaluated for every new instance
+                            let mut default = ASTNode::new_noop();
+                            if self.curr_token?.kind == TokenType::EQL {
+                                self.advance();
+                                default = self.parse_required(Parser::parse_comp_expr)?;
+                            }
+                            let prop_def = ASTNode::new(AST::VAR_DEF { name: prop_name.clone(), value: Box::new(default) }, prop_e);
+                            let props : &mut Vec<(String, ASTNode)> = if is_static { &mut static_properties } else { &mut properties };
+                            //a property declared twice keeps only its last declaration
+                            props.retain(|(name, _)| name != prop_name);
+                            props.push((prop_name.clone(), prop_def));
+                            if is_private {
+                                private.insert(prop_name.clone());
+                            }
+                            self.verify(TokenType::SEMI)?;
                             self.advance();
                         } else {
                             //expected name of property error
-                            self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::SyntaxError, "Expected name of property", self.curr_token?.einfo.clone()));
-                            self.errorstack.borrow().terminate_gs();
+                            self.error(&EXPECTED_NAME, "Expected name of property", self.curr_token?.einfo.clone());
+                            return None;
                         }
                     },
                     "method" => {
                         let mdef = self.parse_function_definition()?;
                         if let AST::FUNC_DEF { body: _, name, args: _ } = &mdef.kind {
-                            methods.insert(name.clone(), mdef.clone());
+                            if is_static {
+                                static_methods.insert(name.clone(), mdef.clone());
+                            } else {
+                                methods.insert(name.clone(), mdef.clone());
+                            }
+                            if is_private {
+                                private.insert(name.clone());
+                            }
                         } else {
                             //improper method definition error
-                            self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::SyntaxError, "Invalid method definition", mdef.einfo.clone()));
-                            self.errorstack.borrow().terminate_gs();
+                            self.error(&INVALID_BLUEPRINT_MEMBER, "Invalid method definition", mdef.einfo.clone());
+                        }
+                        self.verify(TokenType::SEMI)?;
+                        self.advance();
+                    },
+                    "get" | "set" => {
+                        let is_getter = id_value == "get";
+                        if is_static {
+                            self.errorstack.borrow_mut().errors.push(GError::from_code(&INVALID_ACCESSOR, "Getters and setters cannot be static", self.curr_token?.einfo.clone()));
                         }
-                        self.verify(TokenType::SEMI);
+                        let adef = self.parse_function_definition()?;
+                        if let AST::FUNC_DEF { body: _, name, args } = &adef.kind {
+                            //getters take no parameters, setters take the value being assigned
+                            if is_getter && !args.is_empty() {
+                                self.errorstack.borrow_mut().errors.push(GError::from_code(&INVALID_ACCESSOR, format!("Getter '{}' cannot take any parameters", name).as_str(), adef.einfo.clone()));
+                            } else if !is_getter && args.len() != 1 {
+                                self.errorstack.borrow_mut().errors.push(GError::from_code(&INVALID_ACCESSOR, format!("Setter '{}' requires exactly 1 parameter", name).as_str(), adef.einfo.clone()));
+                            }
+                            if is_private {
+                                private.insert(name.clone());
+                            }
+                            if is_getter {
+                                getters.insert(name.clone(), adef.clone());
+                            } else {
+                                setters.insert(name.clone(), adef.clone());
+                            }
+                        }
+                        self.verify(TokenType::SEMI)?;
                         self.advance();
                     },
                     _ => {
                         //expected 'prop' or 'method' error
-                        self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::SyntaxError, "Expected 'prop' or 'method' to define blueprint fields", self.curr_token?.einfo.clone()));
-                        self.errorstack.borrow().terminate_gs();
+                        self.error(&INVALID_BLUEPRINT_MEMBER, "Expected 'prop', 'method', 'get' or 'set' to define blueprint fields", self.curr_token?.einfo.clone());
                         return None;
                     }
                 }
             } else {
                 //syntax error
-                self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::SyntaxError, "Expected 'prop' or 'method' to define blueprint fields", self.curr_token?.einfo.clone()));
-                self.errorstack.borrow().terminate_gs();
+                self.error(&INVALID_BLUEPRINT_MEMBER, "Expected 'prop', 'method', 'get' or 'set' to define blueprint fields", self.curr_token?.einfo.clone());
                 return None;
             }
         }
         self.advance();
-        Some(ASTNode::new(AST::CLASS{name, properties, methods}, e))
+        Some(ASTNode::new(AST::CLASS{name, properties, methods, getters, setters, static_properties, static_methods, private, statics: None}, e))
     }
     pub fn parse_new(&mut self) -> Option<ASTNode> {
         self.advance(); //past 'new'
-        let name = match &self.curr_token?.kind {
+        let mut name = match &self.curr_token?.kind {
             TokenType::ID(x) => x.clone(),
-            _ => String::new() //expected blueprint name error
+            _ => {
+                self.error(&EXPECTED_NAME, "Expected the name of a blueprint after 'new'", self.curr_token?.einfo.clone());
+                return None;
+            }
         };
-        let e = self.curr_token?.einfo.clone();
+        let mut e = self.curr_token?.einfo.clone();
         self.advance();
-        self.verify(TokenType::LPR);
+        //blueprints from imported modules are qualified by the module name, like 'new mod.Thing()'
+        let mut module_path = Vec::new();
+        while self.curr_token?.kind == TokenType::DOT {
+            self.advance();
+            module_path.push(name);
+            name = match &self.curr_token?.kind {
+                TokenType::ID(x) => x.clone(),
+                _ => {
+                    self.error(&EXPECTED_NAME, "Expected blueprint name after '.'", self.curr_token?.einfo.clone());
+                    return None;
+                }
+            };
+            e = self.curr_token?.einfo.clone();
+            self.advance();
+        }
+        self.verify(TokenType::LPR)?;
         self.advance();
         let mut args = Vec::new();
         if self.curr_token?.kind != TokenType::RPR {
-            args.push(self.parse_comp_expr().unwrap_or(ASTNode::new_noop()));
+            args.push(self.parse_required(Parser::parse_comp_expr)?);
             while let Some(curr_tok) = self.curr_token {
                 if curr_tok.kind != TokenType::CMA {
                     break;
                 } else {
                     self.advance();
-                    args.push(self.parse_comp_expr().unwrap_or(ASTNode::new_noop()));
+                    args.push(self.parse_required(Parser::parse_comp_expr)?);
                 }
             }
         }
-        self.verify(TokenType::RPR);
+        self.verify(TokenType::RPR)?;
         self.advance();
-        Some(ASTNode::new(AST::NEW{name: name.clone(), args}, e))
+        Some(ASTNode::new(AST::NEW{name: name.clone(), module_path, args}, e))
     }
     pub fn parse_if(&mut self) -> Option<ASTNode> {
         let e = self.curr_token?.einfo.clone();
         self.advance(); //past 'if'
-        self.verify(TokenType::LPR);
+        self.verify(TokenType::LPR)?;
         self.advance();
         let mut conds : Vec<ASTNode> = Vec::new();
-        conds.push(self.parse_comp_expr().unwrap_or(ASTNode::new_noop()));
-        self.verify(TokenType::RPR);
+        conds.push(self.parse_required(Parser::parse_comp_expr)?);
+        self.verify(TokenType::RPR)?;
         self.advance();
-        self.verify(TokenType::LBR);
+        self.verify(TokenType::LBR)?;
         self.advance();
         let mut bodies : Vec<ASTNode> = Vec::new();
         bodies.push(self.parse_compound().unwrap_or(ASTNode::new_noop()));
-        self.verify(TokenType::RBR);
+        self.verify(TokenType::RBR)?;
         self.advance();
         while self.curr_token.is_some() && self.curr_token?.kind == TokenType::ID("else".to_owned()) {
             self.advance(); //past 'else'
             if self.curr_token?.kind == TokenType::ID("if".to_owned()) {
                 self.advance(); //past 'if'
-                self.verify(TokenType::LPR);
+                self.verify(TokenType::LPR)?;
                 self.advance();
-                conds.push(self.parse_comp_expr().unwrap_or(ASTNode::new_noop()));
-                self.verify(TokenType::RPR);
+                conds.push(self.parse_required(Parser::parse_comp_expr)?);
+                self.verify(TokenType::RPR)?;
                 self.advance();
-                self.verify(TokenType::LBR);
+                self.verify(TokenType::LBR)?;
                 self.advance();
                 bodies.push(self.parse_compound().unwrap_or(ASTNode::new_noop()));
-                self.verify(TokenType::RBR);
+                self.verify(TokenType::RBR)?;
                 self.advance();
 
             } else {
@@ -610,10 +843,10 @@ impl<'a> Parser<'a> {
         };
         let mut else_body = None;
         if self.prev_token?.kind == TokenType::ID("else".to_owned()) {
-            self.verify(TokenType::LBR);
+            self.verify(TokenType::LBR)?;
             self.advance();
             else_body = Some(Box::new(self.parse_compound().unwrap_or(ASTNode::new_noop())));
-            self.verify(TokenType::RBR);
+            self.verify(TokenType::RBR)?;
             self.advance();
         }
         Some(ASTNode::new(AST::IF { conditions : conds, bodies, else_body }, e))
@@ -622,15 +855,15 @@ impl<'a> Parser<'a> {
     pub fn parse_while(&mut self) -> Option<ASTNode> {  
         let e = self.curr_token?.einfo.clone();
         self.advance(); //past 'while'
-        self.verify(TokenType::LPR);
+        self.verify(TokenType::LPR)?;
         self.advance();
-        let cond = self.parse_comp_expr().unwrap_or(ASTNode::new_noop());
-        self.verify(TokenType::RPR);
+        let cond = self.parse_required(Parser::parse_comp_expr)?;
+        self.verify(TokenType::RPR)?;
         self.advance();
-        self.verify(TokenType::LBR);
+        self.verify(TokenType::LBR)?;
         self.advance();
         let b = self.parse_compound().unwrap_or(ASTNode::new_noop());
-        self.verify(TokenType::RBR);
+        self.verify(TokenType::RBR)?;
         self.advance();
         Some(ASTNode::new(AST::WHILE { condition: Box::new(cond), body: Box::new(b)}, e))
     }
@@ -645,7 +878,7 @@ impl<'a> Parser<'a> {
             self.advance();
             match &tok.kind {
                 TokenType::STRING(s) => { return Some(ASTNode::new(AST::STRING { str_value : s.to_string() }, tok.einfo.clone())); }
-                _ => { self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::TokenError, "Expected token 'STRING'", tok.einfo.clone()));
+                _ => { self.error(&EXPECTED_TOKEN, "Expected token 'STRING'", tok.einfo.clone());
                 return None; }
             }; 
         } else {
@@ -659,7 +892,7 @@ impl<'a> Parser<'a> {
             match tok.kind {
                 TokenType::INT(x) => { return Some(ASTNode::new(AST::INT { int_value : x}, tok.einfo.clone()))}
                 TokenType::FLOAT(x) => {return Some(ASTNode::new(AST::FLOAT { float_value: x }, tok.einfo.clone()))}
-                _ => { self.errorstack.borrow_mut().errors.push(GError::new_from_tok(ETypes::TokenError, "Expected token 'INT' or 'FLOAT'", tok.einfo.clone()));
+                _ => { self.error(&EXPECTED_TOKEN, "Expected token 'INT' or 'FLOAT'", tok.einfo.clone());
                 return None; }
             }
         } else {
@@ -668,11 +901,13 @@ impl<'a> Parser<'a> {
     }
 
     fn _warn_semi(&mut self) {
-        if self.curr_token.is_some() && self.curr_token.unwrap().kind != TokenType::SEMI {
-            let mut e = self.prev_token.unwrap().einfo.clone();
-            self.errorstack.borrow_mut().flag(EFlags::Semicolon);
+        if let (Some(curr), Some(prev)) = (self.curr_token, self.prev_token) {
+            if curr.kind == TokenType::SEMI {
+                return;
+            }
+            let mut e = prev.einfo.clone();
             e.set_endln();
-            self.errorstack.borrow().warn(e, "Did you mean to put a semicolon here?");
+            self.errorstack.borrow_mut().warn(Warning::MissingSemicolon, e, "Did you mean to put a semicolon here?");
         }
     }
     
@@ -38,6 +38,15 @@ pub struct Token {
     pub kind : TokenType,
     pub einfo : ErrorInfo,
 }
+///a '\ ... \' comment, which the parser never sees but the formatter keeps
+pub struct Comment {
+    //including both backslashes
+    pub text : String,
+    //the index of the token that comes after it
+    pub before : usize,
+    pub line : usize,
+    pub end_line : usize,
+}
 impl Token {
     pub fn new(kind : TokenType, einfo : ErrorInfo) -> Token {
         Token {
@@ -0,0 +1,43 @@
+use gscriptrust::ast::*;
+use gscriptrust::error::*;
+use gscriptrust::parser::*;
+use gscriptrust::visitor::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+//runs a script from tests/scripts and returns the integers in the list it returns at the top level
+//with 'preload_std' the standard library is run first, like it is for 'gscript run'
+pub fn run_script(filename: &str, preload_std: bool) -> Vec<i32> {
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    let ast_compound = Parser::parse_file(&format!("tests/scripts/{}", filename), Rc::clone(&errorstack))
+        .unwrap_or_else(|_| panic!("{} could not be read", filename));
+    let mut visitor = Visitor::new(Rc::clone(&errorstack));
+    let res = if preload_std {
+        visitor.preload_std().and_then(|_| visitor.visit(&ast_compound))
+    } else {
+        visitor.visit(&ast_compound)
+    };
+    let res = match res {
+        Ok(res) => res,
+        Err(e) => {
+            errorstack.borrow_mut().errors.push(e);
+            errorstack.borrow().print_dump();
+            panic!("{} stopped with an error", filename);
+        }
+    };
+    errorstack.borrow().print_dump();
+    assert!(errorstack.borrow().errors.is_empty(), "{} reported errors", filename);
+    let AST::RETURN { value } = res.kind else {
+        panic!("{} did not return a value", filename);
+    };
+    let AST::LIST { contents } = value.kind else {
+        panic!("{} did not return a list", filename);
+    };
+    contents
+        .iter()
+        .map(|c| match c.borrow().kind {
+            AST::INT { int_value } => int_value,
+            ref other => panic!("expected an integer, found {:?}", other),
+        })
+        .collect()
+}
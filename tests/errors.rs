@@ -0,0 +1,106 @@
+use gscriptrust::codes::*;
+use gscriptrust::error::*;
+use gscriptrust::parser::*;
+use gscriptrust::warnings::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn errors_serialize_to_one_line_of_json() {
+    let einfo = ErrorInfo::new("dir\\a.gsc".to_string(), "write(\"x\");".to_string(), 3, 6, 9);
+    let error = GError::new_from_tok(ETypes::TypeError, "Expected \"String\"\nnot Integer", einfo.clone())
+        .with_trace(&[("f".to_string(), einfo)]);
+    assert_eq!(
+        error.to_json(),
+        "{\"severity\":\"error\",\"kind\":\"TypeError\",\"code\":null,\"message\":\"Expected \\\"String\\\"\\nnot Integer\",\"file\":\"dir\\\\a.gsc\",\"line\":3,\"col\":6,\"col_end\":9,\"trace\":[{\"function\":\"f\",\"file\":\"dir\\\\a.gsc\",\"line\":3,\"col\":6}]}"
+    );
+}
+
+#[test]
+fn warnings_serialize_with_their_name_and_code() {
+    let einfo = ErrorInfo::new("a.gsc".to_string(), "assign x = 1".to_string(), 2, 12, 13);
+    let warning = GWarning { kind : Warning::MissingSemicolon, message : "Expected ';'".to_string(), einfo };
+    assert_eq!(
+        warning.to_json(),
+        "{\"severity\":\"warning\",\"kind\":\"missing-semicolon\",\"code\":\"G0104\",\"message\":\"Expected ';'\",\"file\":\"a.gsc\",\"line\":2,\"col\":12,\"col_end\":13}"
+    );
+}
+
+#[test]
+fn error_codes_are_unique_and_match_their_kind() {
+    let kinds = [
+        ETypes::SyntaxError, ETypes::DivideByZeroError, ETypes::FileError, ETypes::TokenError, ETypes::EndOfInputError,
+        ETypes::VariableDefinitionError, ETypes::FunctionDefinitionError, ETypes::FunctionError, ETypes::ConditionalError,
+        ETypes::TypeError, ETypes::ListError, ETypes::BlueprintError, ETypes::IdentifierError, ETypes::ImportError,
+    ];
+    for (i, code) in ALL.iter().enumerate() {
+        assert_eq!(code.code.len(), 5, "{}", code.code);
+        assert!(code.code.starts_with('G') && code.code[1..].chars().all(|c| c.is_ascii_digit()), "{}", code.code);
+        let kind : usize = code.code[1..3].parse().unwrap();
+        assert_eq!(kinds[kind-1], code.etype, "{}", code.code);
+        assert!(ALL[i+1..].iter().all(|other| other.code != code.code), "{} is used twice", code.code);
+        assert!(!code.summary.is_empty() && code.explanation.contains("Fix:"), "{}", code.code);
+    }
+}
+
+#[test]
+fn error_codes_are_looked_up_and_attached_to_errors() {
+    assert_eq!(lookup("g0104").map(|c| c.code), Some(EXPECTED_SEMICOLON.code));
+    assert!(lookup("G9999").is_none());
+
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    Parser::parse_source("<test>", "assign x = 1\nwrite(x);", Rc::clone(&errorstack));
+    let errors = &errorstack.borrow().errors;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code(), Some("G0104"));
+    assert!(errors[0].to_json().contains("\"code\":\"G0104\""));
+}
+
+#[test]
+fn warnings_are_deferred_and_follow_their_level() {
+    let source = "assign x = 1\nwrite(x);";
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    Parser::parse_source("<test>", source, Rc::clone(&errorstack));
+    assert_eq!(errorstack.borrow().warnings.len(), 1);
+    assert_eq!(errorstack.borrow().warnings[0].kind, Warning::MissingSemicolon);
+    assert_eq!(errorstack.borrow().summary().as_deref(), Some("1 warning and 1 error"));
+
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    errorstack.borrow_mut().config.set("all", Level::Allow).unwrap();
+    Parser::parse_source("<test>", source, Rc::clone(&errorstack));
+    assert!(errorstack.borrow().warnings.is_empty());
+    assert_eq!(errorstack.borrow().errors.len(), 1);
+
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    errorstack.borrow_mut().config.set("missing-semicolon", Level::Deny).unwrap();
+    Parser::parse_source("<test>", source, Rc::clone(&errorstack));
+    assert!(errorstack.borrow().warnings.is_empty());
+    assert_eq!(errorstack.borrow().summary().as_deref(), Some("2 errors"));
+    //a denied warning is reported with the code of its kind
+    let errors = &errorstack.borrow().errors;
+    let denied = errors.iter().find(|e| e.to_json().contains("is denied")).unwrap();
+    assert_eq!(denied.code(), Some(Warning::MissingSemicolon.code().code));
+    assert!(denied.to_json().contains("\"code\":\"G0104\""));
+}
+
+#[test]
+fn every_warning_has_a_code_of_its_kind() {
+    for warning in Warning::ALL {
+        assert!(ALL.iter().any(|code| code.code == warning.code().code), "{}", warning.name());
+        assert_eq!(warning.code().etype, warning.etype(), "{}", warning.name());
+    }
+}
+
+#[test]
+fn warning_config_reads_levels_from_a_file() {
+    let mut config = WarningConfig::new();
+    config.parse("# semicolons are required here\nall = allow\nmissing-semicolon = deny # but not strings\n\n", "gscript.cfg").unwrap();
+    assert_eq!(config.level(Warning::MissingSemicolon), Level::Deny);
+    assert_eq!(config.level(Warning::UnclosedString), Level::Allow);
+    assert_eq!(WarningConfig::new().level(Warning::UnclosedString), Level::Warn);
+
+    let e = config.parse("missing-semicolon = deny\nmissing-semicolons = deny", "gscript.cfg").unwrap_err();
+    assert!(e.starts_with("gscript.cfg: Line 2: Unknown warning 'missing-semicolons'"), "{}", e);
+    assert!(config.parse("unclosed-string = error", "gscript.cfg").is_err());
+    assert!(config.parse("unclosed-string", "gscript.cfg").is_err());
+}
@@ -0,0 +1,77 @@
+use gscriptrust::error::*;
+use gscriptrust::formatter::*;
+use std::cell::RefCell;
+use std::fs;
+use std::rc::Rc;
+
+fn format(source : &str) -> Option<String> {
+    Formatter::format_source("<test>", source, Rc::new(RefCell::new(ErrorStack::new())))
+}
+
+#[test]
+fn formatter_writes_the_canonical_style() {
+    let source = "import {a as b,c} from \"m.gsc\";import \"m.gsc\"->m;
+\\ header \\
+funct   f(param x,param y){ \\ trailing \\
+  if(x>-1&!y){return -x*(y-2)}else if (x==2) {return [1,[ 2 ],3][0]}else{ ;;return 1.50};
+ 
+ 
+
+  \\ own line \\
+  return 0 };;
+blueprint B{ private prop p=1.0 ; method create(){}; get p(){return p};};
+write(f(1,true), 'say \"hi\"', m.g(), new B())";
+    let expected = "import { a as b, c } from \"m.gsc\";
+import \"m.gsc\" -> m;
+\\ header \\
+funct f(param x, param y) { \\ trailing \\
+    if(x > -1 & !y) {
+        return -x * (y - 2);
+    } else if(x == 2) {
+        return [1, [2], 3][0];
+    } else {
+        return 1.5;
+    };
+
+    \\ own line \\
+    return 0;
+};
+blueprint B {
+    private prop p = 1.0;
+    method create() {};
+    get p() {
+        return p;
+    };
+};
+write(f(1, true), 'say \"hi\"', m.g(), new B());
+";
+    let formatted = format(source).unwrap();
+    assert_eq!(formatted, expected);
+    assert_eq!(format(&formatted).unwrap(), formatted);
+}
+
+#[test]
+fn formatter_keeps_line_endings_and_the_shebang() {
+    let formatted = format("#!/usr/bin/env gscript\r\nwrite( 1 )\r\n").unwrap();
+    assert_eq!(formatted, "#!/usr/bin/env gscript\r\nwrite(1);\r\n");
+}
+
+#[test]
+fn formatter_rejects_syntax_errors() {
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    assert!(Formatter::format_source("<test>", "assign = ;", Rc::clone(&errorstack)).is_none());
+    assert!(!errorstack.borrow().errors.is_empty());
+}
+
+#[test]
+fn formatter_leaves_std_and_entry_unchanged() {
+    for dir in ["std", "entry"] {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|e| e == "gsc") {
+                let source = fs::read_to_string(&path).unwrap();
+                assert_eq!(format(&source).as_deref(), Some(source.as_str()), "{} is not formatted", path.display());
+            }
+        }
+    }
+}
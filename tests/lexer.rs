@@ -0,0 +1,23 @@
+use gscriptrust::lexer::*;
+use gscriptrust::error::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn lexer_skips_shebang_line() {
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    let mut lexer = Lexer::from_source("<stdin>", "#!/usr/bin/env gscript\nassign x = 1;\n", Rc::clone(&errorstack));
+    lexer.lex();
+    assert!(errorstack.borrow().errors.is_empty());
+    let first = &lexer.tokens[0];
+    assert_eq!(first.einfo.file, "<stdin>");
+    assert_eq!(first.einfo.line, 2);
+    assert_eq!(first.einfo.col, 1);
+}
+
+#[test]
+fn lexer_returns_error_for_missing_file() {
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    assert!(Lexer::new("tests/scripts/does_not_exist.gsc", Rc::clone(&errorstack)).is_err());
+    assert!(errorstack.borrow().errors.is_empty());
+}
@@ -0,0 +1,96 @@
+use gscriptrust::error::*;
+use gscriptrust::lint::*;
+use gscriptrust::parser::*;
+use gscriptrust::warnings::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Found = (Vec<(usize, Warning)>, Vec<(usize, &'static str)>);
+
+//lints 'source' and returns the (line, warning) pairs and the (line, code) pairs it found
+fn lint(source : &str) -> Found {
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    let ast_compound = Parser::parse_source("<test>", source, Rc::clone(&errorstack));
+    assert!(errorstack.borrow().errors.is_empty(), "the test script has syntax errors");
+    Linter::lint(&ast_compound, &errorstack);
+    let errorstack = errorstack.borrow();
+    (
+        errorstack.warnings.iter().map(|w| (w.einfo.line, w.kind)).collect(),
+        errorstack.errors.iter().map(|e| (e.line(), e.code().unwrap())).collect(),
+    )
+}
+
+#[test]
+fn lint_reports_unused_names_and_unreachable_code() {
+    let source = "assign unused = 1;
+assign _kept = 2;
+export assign shared = 3;
+assign total = 0;
+funct add(param a, param b) {
+    return a;
+    write(b);
+};
+funct loop(param n) {
+    while (true) {
+        break;
+        n = 0;
+    };
+};
+total = add(total, 1);
+loop(3);
+write(total);";
+    let (warnings, errors) = lint(source);
+    assert_eq!(warnings, vec![
+        (1, Warning::UnusedVariable),
+        (7, Warning::UnreachableCode),
+        (9, Warning::UnusedParameter),
+        (12, Warning::UnreachableCode),
+    ]);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn lint_checks_calls_blueprints_and_shadowing() {
+    let source = "assign count = 0;
+write(twice(count), twice(1, 2), twise(3));
+funct twice(param x) {
+    assign count = x * 2;
+    return count;
+};
+blueprint Point {
+    prop x;
+    method create() {};
+    method move(param x) {
+        return shift(x);
+    };
+    method shift(param by) {
+        return by;
+    };
+};
+blueprint Empty {
+    prop p;
+};
+write(new Point(1));";
+    let (warnings, errors) = lint(source);
+    assert_eq!(warnings, vec![(4, Warning::ShadowedVariable), (17, Warning::MissingConstructor)]);
+    //twice is defined after the call, and 'shift' is found as a method of the same blueprint
+    assert_eq!(errors, vec![(2, "G0801"), (2, "G0802"), (20, "G0801")]);
+}
+
+#[test]
+fn lint_only_reports_shadowing_of_earlier_variables() {
+    let source = "assign before = 1;
+funct f(param before, param after) {
+    assign inner = before + after;
+    funct g(param inner, param late) {
+        return inner + late;
+    };
+    assign late = g(inner, 1);
+    return late;
+};
+assign after = f(before, 2);
+write(after);";
+    let (warnings, _) = lint(source);
+    //'after' and the outer 'late' are defined after the functions with parameters of the same name
+    assert_eq!(warnings, vec![(2, Warning::ShadowedVariable), (4, Warning::ShadowedVariable)]);
+}
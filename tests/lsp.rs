@@ -0,0 +1,71 @@
+use gscriptrust::analysis::*;
+use gscriptrust::lsp::*;
+
+const SOURCE : &str = "blueprint Car {
+    prop speed = 0;
+    private prop secret = 1;
+    method create(param s) {
+        speed = s;
+    };
+    method drive(param by) {
+        return speed + by;
+    };
+};
+funct add(param a, param b) {
+    return a + b;
+};
+assign car = new Car(2);
+assign name = \"gs\";
+write(add(1, 2), car.drive(1), name.length());";
+
+#[test]
+fn analysis_finds_definitions_and_signatures() {
+    let analysis = Analysis::analyze("test.gsc", SOURCE);
+    //'add' in 'write(add(1, 2), ...'
+    let add = analysis.symbol_at(16, 8).unwrap();
+    assert_eq!((add.kind, add.einfo.line), (SymbolKind::Function, 11));
+    assert_eq!(add.signature(), "funct add(param a, param b)");
+    //'b' in 'return a + b;'
+    let b = analysis.symbol_at(12, 16).unwrap();
+    assert_eq!((b.kind, b.einfo.line), (SymbolKind::Parameter, 11));
+    //'drive' in 'car.drive(1)', through the blueprint of the value assigned to 'car'
+    let drive = analysis.symbol_at(16, 22).unwrap();
+    assert_eq!((drive.kind, drive.einfo.line, drive.blueprint.as_deref()), (SymbolKind::Method, 7, Some("Car")));
+    //'Car' in 'new Car(2)' is created with the parameters of 'create'
+    assert_eq!(analysis.symbol_at(14, 18).unwrap().signature(), "blueprint Car\nnew Car(param s)");
+    //'length' is a method of '_string' in std/string.gsc
+    let length = analysis.symbol_at(16, 37).unwrap();
+    assert_eq!((length.blueprint.as_deref(), length.einfo.file.as_str()), (Some("_string"), "std/string.gsc"));
+    assert_eq!(analysis.hover(16, 2).unwrap().0, "write(args<AnyType>...) -> <NoOperation>");
+}
+
+#[test]
+fn analysis_completes_members_and_names() {
+    let source = "assign s = \"text\";\nassign n = s.\nassign c = new Car();\nc.sp\nblueprint Car { prop speed; private prop secret; method create() {}; };\nw";
+    let analysis = Analysis::analyze("test.gsc", source);
+    let labels = |line, col| -> Vec<String> { analysis.completions(line, col).into_iter().map(|s| s.name).collect() };
+    assert_eq!(labels(2, 14), ["length", "char_at", "contains", "index_of"]);
+    assert_eq!(labels(4, 5), ["speed"]);
+    let names = labels(6, 2);
+    assert!(names.contains(&"write".to_string()) && names.contains(&"while".to_string()));
+    assert!(labels(7, 1).contains(&"Car".to_string()) && !labels(7, 1).contains(&"_string".to_string()));
+}
+
+#[test]
+fn server_publishes_diagnostics_and_answers_requests() {
+    let mut server = LanguageServer::new();
+    let reply = server.handle(&Json::parse(r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#).unwrap());
+    assert_eq!(reply[0].get("result").get("capabilities").get("hoverProvider"), &Json::Bool(true));
+    let open = r#"{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{"textDocument":{"uri":"file:///tmp/lsp%20test.gsc","text":"assign x = 1;\nwrite(x);\nnope();"}}}"#;
+    let reply = server.handle(&Json::parse(open).unwrap());
+    let diagnostics = reply[0].get("params").get("diagnostics").as_array();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].get("code").as_str(), Some("G0802"));
+    assert_eq!(diagnostics[0].get("range").get("start").get("line").as_usize(), Some(2));
+    let definition = r#"{"jsonrpc":"2.0","id":2,"method":"textDocument/definition","params":{"textDocument":{"uri":"file:///tmp/lsp%20test.gsc"},"position":{"line":1,"character":6}}}"#;
+    let reply = server.handle(&Json::parse(definition).unwrap());
+    assert_eq!(reply[0].get("result").get("range").get("start"), &Json::parse(r#"{"line":0,"character":7}"#).unwrap());
+    let reply = server.handle(&Json::parse(r#"{"jsonrpc":"2.0","id":3,"method":"unknown"}"#).unwrap());
+    assert_eq!(reply[0].get("error").get("code"), &Json::Number(-32601.0));
+    assert_eq!(uri_to_path("file:///tmp/lsp%20test.gsc"), "/tmp/lsp test.gsc");
+}
@@ -0,0 +1,41 @@
+mod common;
+use common::run_script;
+use gscriptrust::modules::*;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn imports_resolve_relative_to_the_importing_file() {
+    //shapes.gsc re-exports from counter.gsc, which sits next to it rather than next to modules.gsc
+    assert_eq!(run_script("modules.gsc", true), vec![12, 15, 1, 21, 31, 1]);
+}
+
+#[test]
+fn static_members_are_found_through_modules() {
+    assert_eq!(run_script("module_statics.gsc", true), vec![3, 12]);
+}
+
+#[test]
+fn std_modules_are_embedded() {
+    assert_eq!(run_script("std_strings.gsc", true), vec![2, 5]);
+}
+
+#[test]
+fn std_imports_use_the_embedded_copy() {
+    let embedded = ModuleSource::Embedded("std/integer.gsc".to_string(), embedded_std("integer.gsc").unwrap());
+    assert_eq!(resolve_import("integer.gsc", "<test>", false), Some(embedded));
+    assert!(matches!(resolve_import("integer.gsc", "<test>", true), Some(ModuleSource::File(_))));
+}
+
+#[test]
+fn every_std_file_is_embedded() {
+    let std_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("std");
+    let mut files : Vec<String> = fs::read_dir(std_dir).unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(".gsc"))
+        .collect();
+    files.sort();
+    let mut embedded : Vec<&str> = STD_MODULES.iter().map(|(name, _)| *name).collect();
+    embedded.sort();
+    assert_eq!(files, embedded);
+}
@@ -0,0 +1,114 @@
+use gscriptrust::error::*;
+use gscriptrust::parser::*;
+use gscriptrust::visitor::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+mod common;
+use common::run_script;
+
+#[test]
+fn methods_mutate_self() {
+    assert_eq!(run_script("self_mutation.gsc", false), vec![5]);
+}
+
+#[test]
+fn methods_mutate_nested_objects() {
+    assert_eq!(run_script("nested_objects.gsc", false), vec![3, 50]);
+}
+
+#[test]
+fn methods_mutate_objects_in_lists() {
+    assert_eq!(run_script("objects_in_lists.gsc", false), vec![60, 8, 11, 121]);
+}
+
+#[test]
+fn private_members_are_usable_in_methods_of_their_blueprint() {
+    assert_eq!(run_script("private_members.gsc", false), vec![20, 13, 16, 9]);
+}
+
+#[test]
+fn property_defaults_run_in_declaration_order() {
+    assert_eq!(run_script("property_defaults.gsc", false), vec![1, 2, 20, 22]);
+}
+
+#[test]
+fn static_defaults_run_in_declaration_order() {
+    assert_eq!(run_script("static_defaults.gsc", false), vec![10, 11, 22, 43]);
+}
+
+#[test]
+fn accessors_use_the_stored_property_through_self() {
+    assert_eq!(run_script("accessors.gsc", false), vec![2, 41, 51]);
+}
+
+#[test]
+fn objects_and_lists_are_shared() {
+    assert_eq!(run_script("references.gsc", false), vec![6, 10, 20, 30]);
+}
+
+#[test]
+fn copies_are_independent() {
+    assert_eq!(run_script("copies.gsc", false), vec![1, 20, 3, 1, 10, 2, 5, 200]);
+}
+
+#[test]
+fn runtime_errors_are_returned_to_the_caller() {
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    let ast_compound = Parser::parse_source("<test>", "assign x = [1];\nassign y = x[4];\nassign z = 1;", Rc::clone(&errorstack));
+    let mut visitor = Visitor::new(Rc::clone(&errorstack));
+    let Err(e) = visitor.visit(&ast_compound) else {
+        panic!("indexing past the end of a list did not fail");
+    };
+    assert_eq!(e.exit_code(), None);
+    //the caller decides whether to report it
+    assert!(errorstack.borrow().errors.is_empty());
+}
+
+#[test]
+fn runtime_errors_record_the_call_stack() {
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    let source = "blueprint Box {\n    prop items;\n    method create() { items = [1]; };\n    method first() { return items[3]; };\n};\nfunct inner(param b) { return b.first(); };\nfunct outer() { return inner(new Box()); };\nouter();";
+    let ast_compound = Parser::parse_source("<test>", source, Rc::clone(&errorstack));
+    let mut visitor = Visitor::new(Rc::clone(&errorstack));
+    let Err(e) = visitor.visit(&ast_compound) else {
+        panic!("indexing past the end of a list did not fail");
+    };
+    let frames: Vec<(&str, usize)> = e.trace().iter().map(|(name, site)| (name.as_str(), site.line)).collect();
+    assert_eq!(frames, vec![("outer", 8), ("inner", 7), ("Box.first", 6)]);
+}
+
+#[test]
+fn private_access_stops_the_script() {
+    for access in ["write(k.secret);", "k.secret = 5;"] {
+        let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+        let source = format!("blueprint Key {{\n    private prop secret = 1;\n    method create() {{}};\n}};\nassign k = new Key();\n{}\nexit(3);", access);
+        let ast_compound = Parser::parse_source("<test>", &source, Rc::clone(&errorstack));
+        let mut visitor = Visitor::new(Rc::clone(&errorstack));
+        let Err(e) = visitor.visit(&ast_compound) else {
+            panic!("'{}' did not fail", access);
+        };
+        assert_eq!((e.code(), e.line(), e.exit_code()), (Some("G1203"), 6, None));
+    }
+}
+
+#[test]
+fn the_scope_is_restored_after_an_error() {
+    //each snippet fails inside a scope that has an 'inner' of its own,
+    //so defining 'inner' afterwards only works if the visitor went back to the global scope
+    let failing = [
+        "assign i = 0;\nwhile (i < 1) {\n    assign inner = 1;\n    i = [1][5];\n};",
+        "blueprint Box {\n    prop inner = [1];\n    method create() {};\n};\nassign b = new Box();\nwrite(b.inner[5]);",
+        "blueprint Box {\n    prop inner = [1];\n    method create() {};\n};\nassign b = new Box();\nb.inner[5] = 1;",
+        "blueprint Box {\n    prop inner = 1;\n    prop bad = [1][5];\n    method create() {};\n};\nassign b = new Box();",
+        "blueprint Box {\n    static prop inner = 1;\n    static prop bad = [1][5];\n};",
+    ];
+    for source in failing {
+        let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+        let mut visitor = Visitor::new(Rc::clone(&errorstack));
+        let ast_compound = Parser::parse_source("<test>", source, Rc::clone(&errorstack));
+        assert!(visitor.visit(&ast_compound).is_err(), "'{}' did not fail", source);
+        let ast_compound = Parser::parse_source("<test>", "assign inner = 2;", Rc::clone(&errorstack));
+        assert!(visitor.visit(&ast_compound).is_ok(), "the scope was not restored after '{}'", source);
+    }
+}
@@ -0,0 +1,47 @@
+use gscriptrust::ast::*;
+use gscriptrust::error::*;
+use gscriptrust::parser::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn parse_source_builds_compound() {
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    let ast_compound = Parser::parse_source("<test>", "assign x = 1;\nx = x + 2;", Rc::clone(&errorstack));
+    assert!(errorstack.borrow().errors.is_empty());
+    let AST::COMPOUND { compound_value } = ast_compound.kind else {
+        panic!("expected a compound");
+    };
+    assert!(matches!(compound_value[0].kind, AST::VAR_DEF { .. }));
+    assert_eq!(compound_value[1].einfo.file, "<test>");
+    assert_eq!(compound_value[1].einfo.line, 2);
+}
+
+#[test]
+fn parser_reports_every_broken_statement() {
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    let source = "assign x = 1 +;\nassign = 4;\nfunct f() {\n    write(x;\n    return x;\n};\nassign y = (2;\nassign z = 3;";
+    let ast_compound = Parser::parse_source("<test>", source, Rc::clone(&errorstack));
+    assert_eq!(errorstack.borrow().errors.len(), 4);
+    //the statements after each error are still parsed
+    let AST::COMPOUND { compound_value } = ast_compound.kind else {
+        panic!("expected a compound");
+    };
+    assert!(compound_value.iter().any(|s| matches!(&s.kind, AST::VAR_DEF { name, .. } if name == "z")));
+}
+
+#[test]
+fn parser_does_not_panic_on_truncated_input() {
+    for source in ["assign", "funct f(", "blueprint B { prop", "if (x) {", "x.", "[1, 2", "new a.", "import {a as"] {
+        let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+        Parser::parse_source("<test>", source, Rc::clone(&errorstack));
+        assert!(!errorstack.borrow().errors.is_empty(), "no error for {:?}", source);
+    }
+}
+
+#[test]
+fn tree_outlines_nodes_with_positions() {
+    let errorstack = Rc::new(RefCell::new(ErrorStack::new()));
+    let ast_compound = Parser::parse_source("<test>", "assign x = 1 + 2;", Rc::clone(&errorstack));
+    assert_eq!(ast_compound.tree(), "compound 1:1\n  var def x 1:8\n    binop PLS 1:14\n      int 1 1:12\n      int 2 1:16\n  eof 1:18\n");
+}
@@ -0,0 +1,36 @@
+use gscriptrust::repl::*;
+
+#[test]
+fn repl_keeps_definitions_between_inputs() {
+    let mut repl = Repl::new(false);
+    assert_eq!(repl.eval("assign x = [1, 2];"), None);
+    assert_eq!(repl.eval("funct f(param a) {\n    return a * 10;\n};"), None);
+    assert_eq!(repl.eval("f(x[1])"), Some("20".to_string()));
+    assert_eq!(repl.eval("\"abc\".length()"), Some("3".to_string()));
+}
+
+#[test]
+fn repl_recovers_from_fatal_errors() {
+    let mut repl = Repl::new(false);
+    repl.eval("funct g() { assign inner = 1; return [1][3]; };");
+    assert_eq!(repl.eval("g()"), None);
+    //the function's scope was left when the error unwound
+    assert_eq!(repl.eval("assign inner = 2;"), None);
+    assert_eq!(repl.eval("inner + 1"), Some("3".to_string()));
+}
+
+#[test]
+fn repl_stops_at_exit() {
+    let mut repl = Repl::new(false);
+    assert_eq!(repl.eval("assign x = 1;"), None);
+    assert_eq!(repl.exit_code, None);
+    repl.eval("exit(x + 2); assign x = 5;");
+    assert_eq!(repl.exit_code, Some(3));
+}
+
+#[test]
+fn repl_waits_for_closing_brackets() {
+    assert!(Repl::is_incomplete("funct f() {"));
+    assert!(Repl::is_incomplete("write(\"}\""));
+    assert!(!Repl::is_incomplete("funct f() { \\ } \\ };"));
+}
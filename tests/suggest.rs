@@ -0,0 +1,23 @@
+use gscriptrust::suggest::*;
+
+#[test]
+fn edit_distance_counts_swaps_as_one_edit() {
+    assert_eq!(edit_distance("write", "write"), 0);
+    assert_eq!(edit_distance("wrtie", "write"), 1);
+    assert_eq!(edit_distance("helpr", "helper"), 1);
+    assert_eq!(edit_distance("", "abc"), 3);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+}
+
+#[test]
+fn closest_only_suggests_likely_typos() {
+    let names = ["counter", "count", "Point", "x"];
+    assert_eq!(closest("coutner", names), Some("counter"));
+    assert_eq!(closest("point", names), Some("Point"));
+    assert_eq!(closest("y", names), None);
+    assert_eq!(closest("total", names), None);
+    assert_eq!(
+        with_suggestion("Variable 'cout' does not exist".to_string(), "cout", names),
+        "Variable 'cout' does not exist. Did you mean 'count'?"
+    );
+}